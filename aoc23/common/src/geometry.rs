@@ -0,0 +1,328 @@
+//! Points and directions on the plane of a grid, where `x` grows to the right and `y` grows
+//! downwards, as rows do.
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A point, or the offset between two points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+
+    /// Return the Manhattan distance to `other`.
+    pub fn manhattan(self, other: Point) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Return the orthogonal neighbours, clockwise from up.
+    pub fn neighbours4(self) -> impl Iterator<Item = Point> {
+        Direction::ALL.into_iter().map(move |d| self + d)
+    }
+
+    /// Return the orthogonal and diagonal neighbours, clockwise from up.
+    pub fn neighbours8(self) -> impl Iterator<Item = Point> {
+        Direction8::ALL.into_iter().map(move |d| self + d)
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, k: i64) -> Point {
+        Point::new(self.x * k, self.y * k)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl Add<Direction> for Point {
+    type Output = Point;
+
+    fn add(self, dir: Direction) -> Point {
+        self + dir.delta()
+    }
+}
+
+impl AddAssign<Direction> for Point {
+    fn add_assign(&mut self, dir: Direction) {
+        *self = *self + dir;
+    }
+}
+
+impl Add<Direction8> for Point {
+    type Output = Point;
+
+    fn add(self, dir: Direction8) -> Point {
+        self + dir.delta()
+    }
+}
+
+impl AddAssign<Direction8> for Point {
+    fn add_assign(&mut self, dir: Direction8) {
+        *self = *self + dir;
+    }
+}
+
+/// One of the four orthogonal directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Every direction, clockwise from up.
+    pub const ALL: [Direction; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    /// Return the offset of a step in this direction.
+    pub const fn delta(self) -> Point {
+        match self {
+            Self::Up => Point::new(0, -1),
+            Self::Right => Point::new(1, 0),
+            Self::Down => Point::new(0, 1),
+            Self::Left => Point::new(-1, 0),
+        }
+    }
+
+    /// Return the direction a quarter turn counterclockwise.
+    pub fn turn_left(self) -> Self {
+        match self {
+            Self::Up => Self::Left,
+            Self::Right => Self::Up,
+            Self::Down => Self::Right,
+            Self::Left => Self::Down,
+        }
+    }
+
+    /// Return the direction a quarter turn clockwise.
+    pub fn turn_right(self) -> Self {
+        match self {
+            Self::Up => Self::Right,
+            Self::Right => Self::Down,
+            Self::Down => Self::Left,
+            Self::Left => Self::Up,
+        }
+    }
+
+    /// Return the opposite direction.
+    pub fn reverse(self) -> Self {
+        match self {
+            Self::Up => Self::Down,
+            Self::Right => Self::Left,
+            Self::Down => Self::Up,
+            Self::Left => Self::Right,
+        }
+    }
+
+    /// Return true for left and right.
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Self::Left | Self::Right)
+    }
+}
+
+impl fmt::Display for Direction {
+    /// Write the direction as an arrow.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Up => write!(f, "^"),
+            Self::Down => write!(f, "v"),
+            Self::Left => write!(f, "<"),
+            Self::Right => write!(f, ">"),
+        }
+    }
+}
+
+impl From<Direction> for Point {
+    fn from(dir: Direction) -> Point {
+        dir.delta()
+    }
+}
+
+/// One of the eight compass directions, north being up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Direction8 {
+    /// Every direction, clockwise from north.
+    pub const ALL: [Direction8; 8] = [
+        Self::N,
+        Self::NE,
+        Self::E,
+        Self::SE,
+        Self::S,
+        Self::SW,
+        Self::W,
+        Self::NW,
+    ];
+
+    /// Return the offset of a step in this direction.
+    pub const fn delta(self) -> Point {
+        match self {
+            Self::N => Point::new(0, -1),
+            Self::NE => Point::new(1, -1),
+            Self::E => Point::new(1, 0),
+            Self::SE => Point::new(1, 1),
+            Self::S => Point::new(0, 1),
+            Self::SW => Point::new(-1, 1),
+            Self::W => Point::new(-1, 0),
+            Self::NW => Point::new(-1, -1),
+        }
+    }
+
+    /// Return the direction `eighths` eighths of a turn clockwise, or counterclockwise when
+    /// negative.
+    pub fn rotate(self, eighths: i32) -> Self {
+        Self::ALL[(self as i32 + eighths).rem_euclid(8) as usize]
+    }
+
+    /// Return the direction an eighth of a turn counterclockwise.
+    pub fn turn_left(self) -> Self {
+        self.rotate(-1)
+    }
+
+    /// Return the direction an eighth of a turn clockwise.
+    pub fn turn_right(self) -> Self {
+        self.rotate(1)
+    }
+
+    /// Return the opposite direction.
+    pub fn reverse(self) -> Self {
+        self.rotate(4)
+    }
+
+    /// Return true for the directions of `Direction`.
+    pub fn is_orthogonal(self) -> bool {
+        (self as usize).is_multiple_of(2)
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(dir: Direction) -> Direction8 {
+        match dir {
+            Direction::Up => Self::N,
+            Direction::Right => Self::E,
+            Direction::Down => Self::S,
+            Direction::Left => Self::W,
+        }
+    }
+}
+
+impl From<Direction8> for Point {
+    fn from(dir: Direction8) -> Point {
+        dir.delta()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn point_arithmetic() {
+        let mut p = Point::new(3, -2);
+        assert_eq!(p + Point::new(1, 1), Point::new(4, -1));
+        assert_eq!(p - Point::new(1, 1), Point::new(2, -3));
+        assert_eq!(p * 3, Point::new(9, -6));
+        assert_eq!(-p, Point::new(-3, 2));
+        assert_eq!(p.manhattan(Point::ORIGIN), 5);
+        p += Direction::Up;
+        p += Direction8::SE;
+        assert_eq!(p, Point::new(4, -2));
+        p -= Point::new(4, -2);
+        assert_eq!(p, Point::ORIGIN);
+        assert_eq!(p.neighbours4().count(), 4);
+        assert!(p
+            .neighbours8()
+            .all(|q| q.x.abs() <= 1 && q.y.abs() <= 1 && q != p));
+    }
+
+    #[test]
+    fn turns() {
+        for d in Direction::ALL {
+            assert_eq!(d.turn_left().turn_right(), d);
+            assert_eq!(d.turn_right().turn_right(), d.reverse());
+            assert_eq!(d.delta() + d.reverse().delta(), Point::ORIGIN);
+            assert_eq!(d.is_horizontal(), d.delta().y == 0);
+        }
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.to_string(), "<");
+    }
+
+    #[test]
+    fn turns8() {
+        for d in Direction8::ALL {
+            assert_eq!(d.turn_left().turn_right(), d);
+            assert_eq!(d.rotate(2).rotate(2), d.reverse());
+            assert_eq!(d.rotate(-9), d.turn_left());
+            assert_eq!(d.delta() + d.reverse().delta(), Point::ORIGIN);
+        }
+        for d in Direction::ALL {
+            let d8 = Direction8::from(d);
+            assert!(d8.is_orthogonal());
+            assert_eq!(d8.delta(), d.delta());
+            assert_eq!(Direction8::from(d.turn_right()), d8.rotate(2));
+        }
+        assert_eq!(Direction8::NW.turn_right(), Direction8::N);
+    }
+}
@@ -0,0 +1,445 @@
+//! Two-dimensional grids of tiles, indexed by row and column.
+
+use crate::geometry::{Direction, Direction8, Point};
+use crate::parse::ParseError;
+use std::error::Error;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// Position of a tile: its row from the top and its column from the left.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Pos {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Pos {
+    pub fn new(row: usize, col: usize) -> Self {
+        Pos { row, col }
+    }
+
+    /// Return the position `delta` away, if neither its row nor its column goes below zero.
+    pub fn offset(self, delta: impl Into<Point>) -> Option<Pos> {
+        let delta = delta.into();
+        Some(Pos {
+            row: self.row.checked_add_signed(delta.y.try_into().ok()?)?,
+            col: self.col.checked_add_signed(delta.x.try_into().ok()?)?,
+        })
+    }
+
+    /// Return the Manhattan distance to `other`.
+    pub fn manhattan(self, other: Pos) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+}
+
+impl fmt::Display for Pos {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.row, self.col)
+    }
+}
+
+impl From<Pos> for Point {
+    /// Return the point of `pos`, its column as `x` and its row as `y`.
+    fn from(pos: Pos) -> Point {
+        Point::new(pos.col as i64, pos.row as i64)
+    }
+}
+
+/// Reasons a grid cannot be built.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    /// A row does not have as many tiles as the first one.
+    Ragged {
+        row: usize,
+        len: usize,
+        expected: usize,
+    },
+    /// A byte of the text is not a tile.
+    Tile { pos: Pos, byte: u8 },
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Ragged { row, len, expected } => write!(
+                f,
+                "row {} has {} tiles, expected {}",
+                row + 1,
+                len,
+                expected
+            ),
+            Self::Tile { pos, byte } => write!(
+                f,
+                "unknown tile {:?} on line {}, column {}",
+                char::from(*byte),
+                pos.row + 1,
+                pos.col + 1
+            ),
+        }
+    }
+}
+
+impl Error for GridError {}
+
+impl GridError {
+    /// Return the error as a parse error about the offending row or tile of `text`, the text the
+    /// grid was parsed from.
+    pub fn in_text(&self, text: &str) -> ParseError {
+        let line = |row: usize| text.lines().nth(row).unwrap_or(&text[text.len()..]);
+        match *self {
+            Self::Ragged { row, len, expected } => ParseError::new(
+                line(row),
+                format!("row has {} tiles, expected {}", len, expected),
+            ),
+            Self::Tile { pos, byte } => {
+                let line = line(pos.row);
+                let tile = line
+                    .get(pos.col..)
+                    .and_then(|rest| rest.chars().next())
+                    .map_or(&line[line.len()..], |c| {
+                        &line[pos.col..pos.col + c.len_utf8()]
+                    });
+                let shown = tile.chars().next().unwrap_or(char::from(byte));
+                ParseError::new(tile, format!("unknown tile {:?}", shown))
+            }
+        }
+    }
+}
+
+/// A rectangular grid of tiles, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    nrow: usize,
+    ncol: usize,
+    tiles: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Return a grid of `nrow` rows and `ncol` columns filled with `fill`.
+    pub fn new(nrow: usize, ncol: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            nrow,
+            ncol,
+            tiles: vec![fill; nrow * ncol],
+        }
+    }
+
+    /// Return the grid of `rows`, which must all have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, GridError> {
+        let nrow = rows.len();
+        let ncol = rows.first().map_or(0, Vec::len);
+        let mut tiles = Vec::with_capacity(nrow * ncol);
+        for (row, tiles_of_row) in rows.into_iter().enumerate() {
+            if tiles_of_row.len() != ncol {
+                return Err(GridError::Ragged {
+                    row,
+                    len: tiles_of_row.len(),
+                    expected: ncol,
+                });
+            }
+            tiles.extend(tiles_of_row);
+        }
+        Ok(Grid { nrow, ncol, tiles })
+    }
+
+    /// Parse a grid with one row per line of `text`, mapping each byte to a tile with `tile`.
+    /// Trailing blank lines are ignored.
+    pub fn parse(text: &str, mut tile: impl FnMut(u8) -> Option<T>) -> Result<Self, GridError> {
+        let rows = text
+            .trim_end_matches(['\n', '\r'])
+            .lines()
+            .enumerate()
+            .map(|(row, line)| {
+                line.bytes()
+                    .enumerate()
+                    .map(|(col, byte)| {
+                        tile(byte).ok_or(GridError::Tile {
+                            pos: Pos::new(row, col),
+                            byte,
+                        })
+                    })
+                    .collect::<Result<Vec<T>, GridError>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if rows.iter().all(Vec::is_empty) {
+            return Ok(Grid {
+                nrow: 0,
+                ncol: 0,
+                tiles: vec![],
+            });
+        }
+        Self::from_rows(rows)
+    }
+
+    /// Return the number of rows.
+    pub fn nrow(&self) -> usize {
+        self.nrow
+    }
+
+    /// Return the number of columns.
+    pub fn ncol(&self) -> usize {
+        self.ncol
+    }
+
+    /// Return true if `pos` is inside the grid.
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.row < self.nrow && pos.col < self.ncol
+    }
+
+    /// Return the tile at `pos`, if it is inside the grid.
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.tiles[pos.row * self.ncol + pos.col])
+    }
+
+    /// Return the tile at `pos` mutably, if it is inside the grid.
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.tiles[pos.row * self.ncol + pos.col])
+        } else {
+            None
+        }
+    }
+
+    /// Return the position `delta` away from `pos`, such as a `Direction`, if it is inside the
+    /// grid.
+    pub fn step(&self, pos: Pos, delta: impl Into<Point>) -> Option<Pos> {
+        pos.offset(delta).filter(|&p| self.contains(p))
+    }
+
+    /// Return the orthogonal neighbours of `pos` inside the grid, clockwise from up.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |d| self.step(pos, d))
+    }
+
+    /// Return the orthogonal and diagonal neighbours of `pos` inside the grid, clockwise from up.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |d| self.step(pos, d))
+    }
+
+    /// Return the tiles of row `row`.
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.tiles[row * self.ncol..(row + 1) * self.ncol]
+    }
+
+    /// Return the rows from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.nrow).map(|row| self.row(row))
+    }
+
+    /// Return the tiles of column `col`, from top to bottom.
+    pub fn col(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.ncol, "column {} out of bounds", col);
+        self.tiles.iter().skip(col).step_by(self.ncol)
+    }
+
+    /// Return the columns from left to right.
+    pub fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.ncol).map(|col| self.col(col))
+    }
+
+    /// Return every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let ncol = self.ncol;
+        (0..self.nrow).flat_map(move |row| (0..ncol).map(move |col| Pos::new(row, col)))
+    }
+
+    /// Return every tile with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.tiles)
+    }
+
+    /// Return the position of the first tile, row by row, that satisfies `pred`.
+    pub fn find(&self, mut pred: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.iter().find(|(_, t)| pred(t)).map(|(pos, _)| pos)
+    }
+
+    /// Return the grid of the tiles mapped by `f`.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            nrow: self.nrow,
+            ncol: self.ncol,
+            tiles: self.tiles.iter().map(f).collect(),
+        }
+    }
+
+    /// Return the grid whose tile at `(row, col)` is `f((row, col))`, of `nrow` rows and `ncol`
+    /// columns.
+    fn from_fn(nrow: usize, ncol: usize, mut f: impl FnMut(Pos) -> T) -> Self {
+        let tiles = (0..nrow)
+            .flat_map(|row| (0..ncol).map(move |col| Pos::new(row, col)))
+            .map(&mut f)
+            .collect();
+        Grid { nrow, ncol, tiles }
+    }
+
+    /// Return the grid mirrored along its main diagonal.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(self.ncol, self.nrow, |p| {
+            self[Pos::new(p.col, p.row)].clone()
+        })
+    }
+
+    /// Return the grid rotated a quarter turn clockwise.
+    pub fn rotate_cw(&self) -> Self
+    where
+        T: Clone,
+    {
+        let last = self.nrow.saturating_sub(1);
+        Self::from_fn(self.ncol, self.nrow, |p| {
+            self[Pos::new(last - p.col, p.row)].clone()
+        })
+    }
+
+    /// Return the grid rotated a quarter turn counterclockwise.
+    pub fn rotate_ccw(&self) -> Self
+    where
+        T: Clone,
+    {
+        let last = self.ncol.saturating_sub(1);
+        Self::from_fn(self.ncol, self.nrow, |p| {
+            self[Pos::new(p.col, last - p.row)].clone()
+        })
+    }
+}
+
+impl Grid<u8> {
+    /// Parse a grid with one row per line of `text`, each byte a tile.
+    pub fn bytes(text: &str) -> Result<Self, GridError> {
+        Self::parse(text, Some)
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("position {} out of bounds", pos))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("position {} out of bounds", pos))
+    }
+}
+
+impl<T: Copy + Into<char>> fmt::Display for Grid<T> {
+    /// Write one line per row, each tile as a character.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            let line: String = row.iter().map(|&t| t.into()).collect();
+            writeln!(f, "{}", line)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "abc\ndef\n";
+
+    #[test]
+    fn parse_bytes() {
+        let grid = Grid::bytes(TEXT).unwrap();
+        assert_eq!((grid.nrow(), grid.ncol()), (2, 3));
+        assert_eq!(grid[Pos::new(1, 0)], b'd');
+        assert_eq!(grid.get(Pos::new(2, 0)), None);
+        assert_eq!(grid.to_string(), TEXT);
+        assert_eq!(
+            Grid::bytes("ab\r\ncd\r\n\n").unwrap().to_string(),
+            "ab\ncd\n"
+        );
+        assert_eq!(Grid::bytes("").unwrap().nrow(), 0);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            Grid::bytes("ab\nc\n"),
+            Err(GridError::Ragged {
+                row: 1,
+                len: 1,
+                expected: 2
+            })
+        );
+        let err = Grid::parse("..\n.x\n", |b| (b == b'.').then_some(())).unwrap_err();
+        assert_eq!(
+            err,
+            GridError::Tile {
+                pos: Pos::new(1, 1),
+                byte: b'x'
+            }
+        );
+        assert_eq!(err.to_string(), "unknown tile 'x' on line 2, column 2");
+        let text = "..\n.x\n";
+        let loc = err.in_text(text).locate(text).location().cloned().unwrap();
+        assert_eq!((loc.line, loc.column, loc.width), (2, 2, 1));
+        let text = "ab\nc\n";
+        let err = Grid::bytes(text).unwrap_err().in_text(text).locate(text);
+        assert_eq!(err.message(), "row has 1 tiles, expected 2");
+        assert_eq!(err.location().map(|loc| loc.line), Some(2));
+    }
+
+    #[test]
+    fn rows_and_cols() {
+        let grid = Grid::bytes(TEXT).unwrap();
+        assert_eq!(grid.row(1), b"def");
+        assert_eq!(grid.rows().collect::<Vec<_>>(), vec![b"abc", b"def"]);
+        assert_eq!(grid.col(2).copied().collect::<Vec<_>>(), b"cf");
+        let cols: Vec<Vec<u8>> = grid.cols().map(|c| c.copied().collect()).collect();
+        assert_eq!(cols, vec![b"ad", b"be", b"cf"]);
+        assert_eq!(grid.find(|&b| b == b'e'), Some(Pos::new(1, 1)));
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = Grid::new(3, 3, 0);
+        let corner: Vec<Pos> = grid.neighbours4(Pos::new(0, 0)).collect();
+        assert_eq!(corner, vec![Pos::new(0, 1), Pos::new(1, 0)]);
+        assert_eq!(grid.neighbours8(Pos::new(0, 2)).count(), 3);
+        assert_eq!(grid.neighbours4(Pos::new(1, 1)).count(), 4);
+        assert_eq!(grid.neighbours8(Pos::new(1, 1)).count(), 8);
+        assert_eq!(grid.step(Pos::new(2, 2), Direction::Right), None);
+        assert_eq!(
+            grid.step(Pos::new(2, 2), Point::new(-2, -1)),
+            Some(Pos::new(1, 0))
+        );
+        assert_eq!(Pos::new(0, 1).offset(Direction8::NW), None);
+        assert_eq!(Point::from(Pos::new(1, 2)), Point::new(2, 1));
+    }
+
+    #[test]
+    fn transpose_and_rotate() {
+        let grid = Grid::bytes(TEXT).unwrap();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+        assert_eq!(grid.transpose().transpose(), grid);
+        let full_turn = (0..4).fold(grid.clone(), |g, _| g.rotate_cw());
+        assert_eq!(full_turn, grid);
+    }
+
+    #[test]
+    fn map_and_set() {
+        let mut grid = Grid::bytes(TEXT).unwrap().map(|&b| b.to_ascii_uppercase());
+        grid[Pos::new(0, 0)] = b'z';
+        assert_eq!(grid.to_string(), "zBC\nDEF\n");
+    }
+}
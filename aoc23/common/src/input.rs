@@ -0,0 +1,310 @@
+//! Loading of puzzle inputs, and normalisation of their text layout so that line endings,
+//! trailing whitespace and byte order marks do not change the answers.
+
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+
+/// Where a puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+    Text(String),
+}
+
+impl Source {
+    /// Return the source named by a command line argument, where `-` stands for stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            Self::Stdin
+        } else {
+            Self::File(PathBuf::from(arg))
+        }
+    }
+
+    /// Read the whole input as a string, normalised with [`normalize`].
+    pub fn load(&self) -> Result<String, InputError> {
+        self.load_with(Mode::Normalize)
+    }
+
+    /// Read the whole input as a string, normalising its layout or, in strict mode, failing if it
+    /// needs any normalisation.
+    pub fn load_with(&self, mode: Mode) -> Result<String, InputError> {
+        let bytes = match self {
+            Self::File(path) => fs::read(path).map_err(|e| self.io_error(e))?,
+            Self::Stdin => {
+                let mut bytes = Vec::new();
+                io::stdin()
+                    .read_to_end(&mut bytes)
+                    .map_err(|e| self.io_error(e))?;
+                bytes
+            }
+            Self::Text(text) => text.clone().into_bytes(),
+        };
+        let text = self.decode(bytes)?;
+        match mode {
+            Mode::Normalize => Ok(normalize(&text)),
+            Mode::Strict => match anomalies(&text).as_slice() {
+                [] => Ok(text),
+                found => Err(InputError::Anomalies {
+                    origin: self.to_string(),
+                    found: found.to_vec(),
+                }),
+            },
+        }
+    }
+
+    fn io_error(&self, error: io::Error) -> InputError {
+        match (self, error.kind()) {
+            (Self::File(path), io::ErrorKind::NotFound) => InputError::Missing(path.clone()),
+            _ => InputError::Io {
+                origin: self.to_string(),
+                error,
+            },
+        }
+    }
+
+    fn decode(&self, bytes: Vec<u8>) -> Result<String, InputError> {
+        let text = String::from_utf8(bytes).map_err(|e| {
+            let valid = &e.as_bytes()[..e.utf8_error().valid_up_to()];
+            InputError::Encoding {
+                origin: self.to_string(),
+                line: valid.iter().filter(|b| **b == b'\n').count() + 1,
+            }
+        })?;
+        if text.trim().is_empty() {
+            return Err(InputError::Empty(self.to_string()));
+        }
+        Ok(text)
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::File(path) => write!(f, "{}", path.display()),
+            Self::Stdin => write!(f, "<stdin>"),
+            Self::Text(_) => write!(f, "<text>"),
+        }
+    }
+}
+
+/// How the loader treats an input whose layout differs from plain text with `\n` line endings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Mode {
+    /// Fix the layout with [`normalize`].
+    #[default]
+    Normalize,
+    /// Report the [`anomalies`] of the layout as an error.
+    Strict,
+}
+
+/// A difference between the layout of an input and plain text with `\n` line endings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Anomaly {
+    ByteOrderMark,
+    CarriageReturn,
+    TrailingWhitespace,
+    MissingFinalNewline,
+    TrailingBlankLines,
+}
+
+impl fmt::Display for Anomaly {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::ByteOrderMark => write!(f, "byte order mark"),
+            Self::CarriageReturn => write!(f, "CRLF line ending"),
+            Self::TrailingWhitespace => write!(f, "trailing whitespace"),
+            Self::MissingFinalNewline => write!(f, "no newline at end of input"),
+            Self::TrailingBlankLines => write!(f, "blank lines at end of input"),
+        }
+    }
+}
+
+/// Return `text` with its byte order mark removed, `\r\n` line endings replaced by `\n`,
+/// trailing whitespace removed from every line, and blank lines at the end replaced by a single
+/// final newline.
+pub fn normalize(text: &str) -> String {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let mut normalized = String::with_capacity(text.len() + 1);
+    for line in text.split('\n') {
+        normalized.push_str(line.trim_end());
+        normalized.push('\n');
+    }
+    normalized.truncate(normalized.trim_end_matches('\n').len());
+    normalized.push('\n');
+    normalized
+}
+
+/// Return each kind of anomaly of `text` that [`normalize`] would fix, with the number of the
+/// first line it is found on, in order of lines.
+pub fn anomalies(text: &str) -> Vec<(usize, Anomaly)> {
+    let mut found: Vec<(usize, Anomaly)> = Vec::new();
+    let mut note = |line: usize, anomaly: Anomaly| {
+        if found.iter().all(|&(_, a)| a != anomaly) {
+            found.push((line, anomaly));
+        }
+    };
+    if text.starts_with('\u{feff}') {
+        note(1, Anomaly::ByteOrderMark);
+    }
+    let lines: Vec<&str> = text.split('\n').collect();
+    for (i, line) in lines.iter().enumerate() {
+        let content = line.strip_suffix('\r');
+        if content.is_some() && i + 1 < lines.len() {
+            note(i + 1, Anomaly::CarriageReturn);
+        }
+        let content = content.unwrap_or(line);
+        if content.trim_end().len() != content.len() {
+            note(i + 1, Anomaly::TrailingWhitespace);
+        }
+    }
+    let body = text.trim_end_matches(|c: char| c.is_whitespace());
+    if !text.ends_with('\n') {
+        note(lines.len(), Anomaly::MissingFinalNewline);
+    } else if text[body.len()..].matches('\n').count() > 1 {
+        note(body.matches('\n').count() + 2, Anomaly::TrailingBlankLines);
+    }
+    found.sort_by_key(|&(line, _)| line);
+    found
+}
+
+/// Reasons a puzzle input cannot be loaded. Each carries the name of its source.
+#[derive(Debug)]
+pub enum InputError {
+    Missing(PathBuf),
+    Io {
+        origin: String,
+        error: io::Error,
+    },
+    Encoding {
+        origin: String,
+        line: usize,
+    },
+    Empty(String),
+    /// The layout needs normalising, in strict mode.
+    Anomalies {
+        origin: String,
+        found: Vec<(usize, Anomaly)>,
+    },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Missing(path) => write!(f, "{}: input file not found", path.display()),
+            Self::Io { origin, error } => write!(f, "{}: {}", origin, error),
+            Self::Encoding { origin, line } => {
+                write!(f, "{}: invalid UTF-8 on line {}", origin, line)
+            }
+            Self::Empty(origin) => write!(f, "{}: input is empty", origin),
+            Self::Anomalies { origin, found } => {
+                write!(f, "{}: ", origin)?;
+                for (i, (line, anomaly)) in found.iter().enumerate() {
+                    let sep = if i == 0 { "" } else { ", " };
+                    write!(f, "{}{} on line {}", sep, anomaly, line)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Load the input file at `path`.
+pub fn load_file(path: &str) -> Result<String, InputError> {
+    Source::File(PathBuf::from(path)).load()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn load_text() {
+        let source = Source::Text("1abc2\n".to_string());
+        assert_eq!(source.load().unwrap(), "1abc2\n");
+    }
+
+    #[test]
+    fn missing_file() {
+        let err = load_file("./no/such/input.txt").unwrap_err();
+        assert!(matches!(err, InputError::Missing(_)));
+        assert_eq!(err.to_string(), "./no/such/input.txt: input file not found");
+    }
+
+    #[test]
+    fn empty_input() {
+        let err = Source::Text(" \n\n".to_string()).load().unwrap_err();
+        assert_eq!(err.to_string(), "<text>: input is empty");
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let err = Source::Stdin
+            .decode(b"ok\nstill ok\nbad \xff byte\n".to_vec())
+            .unwrap_err();
+        assert_eq!(err.to_string(), "<stdin>: invalid UTF-8 on line 3");
+    }
+
+    #[test]
+    fn normalized_layout() {
+        let text = "\u{feff}ab \r\ncd\t\r\n\r\nef";
+        assert_eq!(normalize(text), "ab\ncd\n\nef\n");
+        assert_eq!(normalize("ab\n\n\n \n"), "ab\n");
+        assert_eq!(normalize("ab\n"), "ab\n");
+        let source = Source::Text(text.to_string());
+        assert_eq!(source.load().unwrap(), "ab\ncd\n\nef\n");
+    }
+
+    #[test]
+    fn strict_mode() {
+        let text = "\u{feff}ab\r\ncd \nef";
+        assert_eq!(
+            anomalies(text),
+            vec![
+                (1, Anomaly::ByteOrderMark),
+                (1, Anomaly::CarriageReturn),
+                (2, Anomaly::TrailingWhitespace),
+                (3, Anomaly::MissingFinalNewline),
+            ]
+        );
+        let err = Source::Text(text.to_string())
+            .load_with(Mode::Strict)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "<text>: byte order mark on line 1, CRLF line ending on line 1, \
+             trailing whitespace on line 2, no newline at end of input on line 3"
+        );
+        assert_eq!(
+            anomalies("ab\ncd\n\n\n"),
+            vec![(3, Anomaly::TrailingBlankLines)]
+        );
+        assert_eq!(anomalies("ab\n\ncd\n"), vec![]);
+        let clean = Source::Text("ab\n\ncd\n".to_string());
+        assert_eq!(clean.load_with(Mode::Strict).unwrap(), "ab\n\ncd\n");
+        // Every anomaly is gone once normalised.
+        assert_eq!(anomalies(&normalize(text)), vec![]);
+    }
+
+    #[test]
+    fn source_from_arg() {
+        assert_eq!(Source::from_arg("-"), Source::Stdin);
+        assert_eq!(
+            Source::from_arg("data/input.txt"),
+            Source::File(PathBuf::from("data/input.txt"))
+        );
+    }
+}
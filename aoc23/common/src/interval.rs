@@ -0,0 +1,425 @@
+//! Sets of integers stored as sorted ranges, and maps that shift each range of a partition by its
+//! own offset.
+
+use std::cmp::{max, min};
+use std::error::Error;
+use std::fmt;
+use std::ops::Range;
+
+/// Return `ranges` sorted, without empty ranges, and with overlapping or touching ranges merged.
+pub fn coalesce(mut ranges: Vec<Range<u64>>) -> Vec<Range<u64>> {
+    ranges.retain(|r| !r.is_empty());
+    ranges.sort_by_key(|r| r.start);
+    let mut merged: Vec<Range<u64>> = Vec::with_capacity(ranges.len());
+    for r in ranges {
+        match merged.last_mut() {
+            Some(last) if r.start <= last.end => last.end = max(last.end, r.end),
+            _ => merged.push(r),
+        }
+    }
+    merged
+}
+
+/// Return true if the ranges `a` and `b` share a value.
+pub fn overlap(a: &Range<u64>, b: &Range<u64>) -> bool {
+    a.start < b.end && b.start < a.end
+}
+
+/// Return the values shared by the ranges `a` and `b`, which is empty if they do not overlap.
+pub fn intersect(a: &Range<u64>, b: &Range<u64>) -> Range<u64> {
+    let start = max(a.start, b.start);
+    start..max(start, min(a.end, b.end))
+}
+
+/// A set of integers, stored as sorted, disjoint and non-touching ranges.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    ranges: Vec<Range<u64>>,
+}
+
+impl IntervalSet {
+    /// Return the empty set.
+    pub fn new() -> Self {
+        IntervalSet { ranges: vec![] }
+    }
+
+    /// Return the ranges of the set, in order.
+    pub fn ranges(&self) -> &[Range<u64>] {
+        &self.ranges
+    }
+
+    /// Return true if the set has no value.
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Return the number of values in the set.
+    pub fn len(&self) -> u64 {
+        self.ranges.iter().map(|r| r.end - r.start).sum()
+    }
+
+    /// Return the smallest value of the set.
+    pub fn min(&self) -> Option<u64> {
+        self.ranges.first().map(|r| r.start)
+    }
+
+    /// Return the largest value of the set.
+    pub fn max(&self) -> Option<u64> {
+        self.ranges.last().map(|r| r.end - 1)
+    }
+
+    /// Return true if `value` is in the set.
+    pub fn contains(&self, value: u64) -> bool {
+        let i = self.ranges.partition_point(|r| r.end <= value);
+        self.ranges.get(i).is_some_and(|r| r.start <= value)
+    }
+
+    /// Add the values of `range` to the set.
+    pub fn insert(&mut self, range: Range<u64>) {
+        let mut ranges = std::mem::take(&mut self.ranges);
+        ranges.push(range);
+        self.ranges = coalesce(ranges);
+    }
+
+    /// Return the values in either set.
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        self.ranges.iter().chain(&other.ranges).cloned().collect()
+    }
+
+    /// Return the values in both sets.
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (&self.ranges[i], &other.ranges[j]);
+            let common = intersect(a, b);
+            if !common.is_empty() {
+                ranges.push(common);
+            }
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    /// Return the values of this set that are not in `other`.
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut ranges = Vec::new();
+        let mut j = 0;
+        for r in &self.ranges {
+            let mut start = r.start;
+            // Skip the ranges of `other` that end before this one.
+            while j < other.ranges.len() && other.ranges[j].end <= start {
+                j += 1;
+            }
+            let mut k = j;
+            while k < other.ranges.len() && other.ranges[k].start < r.end {
+                let hole = &other.ranges[k];
+                if start < hole.start {
+                    ranges.push(start..hole.start);
+                }
+                start = max(start, hole.end);
+                k += 1;
+            }
+            if start < r.end {
+                ranges.push(start..r.end);
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    /// Return the values below `at` and the values from `at` on.
+    pub fn split_at(&self, at: u64) -> (IntervalSet, IntervalSet) {
+        let below = IntervalSet::from(0..at);
+        (self.intersection(&below), self.difference(&below))
+    }
+
+    /// Return the set with every value moved by `offset`.
+    ///
+    /// # Panics
+    ///
+    /// If a value would go below zero or past `u64::MAX`.
+    pub fn shift(&self, offset: i64) -> IntervalSet {
+        IntervalSet {
+            ranges: self.ranges.iter().map(|r| shift(r, offset)).collect(),
+        }
+    }
+}
+
+/// Return `range` moved by `offset`, panicking if it leaves the `u64` values.
+fn shift(range: &Range<u64>, offset: i64) -> Range<u64> {
+    moved(range.start, offset)..moved(range.end, offset)
+}
+
+/// Return `value` moved by `offset`, panicking if it leaves the `u64` values.
+fn moved(value: u64, offset: i64) -> u64 {
+    value
+        .checked_add_signed(offset)
+        .unwrap_or_else(|| panic!("moving {} by {} overflows", value, offset))
+}
+
+/// Mapping of a range whose image does not fit in the `u64` values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OverflowError {
+    pub src: Range<u64>,
+    pub dst: u64,
+}
+
+impl fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "mapping {:?} to {} goes past {}",
+            self.src,
+            self.dst,
+            u64::MAX
+        )
+    }
+}
+
+impl Error for OverflowError {}
+
+impl From<Range<u64>> for IntervalSet {
+    fn from(range: Range<u64>) -> Self {
+        [range].into_iter().collect()
+    }
+}
+
+impl FromIterator<Range<u64>> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Range<u64>>>(iter: I) -> Self {
+        IntervalSet {
+            ranges: coalesce(iter.into_iter().collect()),
+        }
+    }
+}
+
+impl fmt::Display for IntervalSet {
+    /// Write the ranges as `{a..b, c..d}`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let ranges: Vec<String> = self.ranges.iter().map(|r| format!("{:?}", r)).collect();
+        write!(f, "{{{}}}", ranges.join(", "))
+    }
+}
+
+/// A map that moves the values of each of its ranges by the offset of that range, and leaves the
+/// values outside them unchanged.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct OffsetMap {
+    /// Disjoint ranges sorted by start, with their offsets.
+    pieces: Vec<(Range<u64>, i64)>,
+}
+
+impl OffsetMap {
+    /// Return the identity map.
+    pub fn new() -> Self {
+        OffsetMap { pieces: vec![] }
+    }
+
+    /// Map the values of `src` to the range starting at `dst`, in order. Values of `src` that are
+    /// already mapped keep their earlier mapping.
+    ///
+    /// Return an error, leaving the map unchanged, if the image of `src` does not fit in the `u64`
+    /// values or is too far from `src` for an `i64` offset. The values of the map can then always
+    /// be moved.
+    pub fn insert(&mut self, src: Range<u64>, dst: u64) -> Result<(), OverflowError> {
+        let offset = i128::from(dst) - i128::from(src.start);
+        let offset = match (i64::try_from(offset), dst.checked_add(src.end - src.start)) {
+            (Ok(offset), Some(_)) => offset,
+            _ => return Err(OverflowError { src, dst }),
+        };
+        let free = IntervalSet::from(src).difference(&self.domain());
+        self.pieces
+            .extend(free.ranges.into_iter().map(|r| (r, offset)));
+        self.pieces.sort_by_key(|(r, _)| r.start);
+        Ok(())
+    }
+
+    /// Return the ranges with their offsets, in order.
+    pub fn pieces(&self) -> &[(Range<u64>, i64)] {
+        &self.pieces
+    }
+
+    /// Return the values that are moved by the map, or explicitly mapped to themselves.
+    pub fn domain(&self) -> IntervalSet {
+        self.pieces.iter().map(|(r, _)| r.clone()).collect()
+    }
+
+    /// Return the image of `value`.
+    pub fn get(&self, value: u64) -> u64 {
+        let i = self.pieces.partition_point(|(r, _)| r.end <= value);
+        match self.pieces.get(i) {
+            Some((r, offset)) if r.start <= value => moved(value, *offset),
+            _ => value,
+        }
+    }
+
+    /// Return the image of every value of `set`.
+    pub fn map_set(&self, set: &IntervalSet) -> IntervalSet {
+        let domain = self.domain();
+        let mut ranges: Vec<Range<u64>> = set.difference(&domain).ranges;
+        for (r, offset) in &self.pieces {
+            let moved = set.intersection(&IntervalSet::from(r.clone()));
+            ranges.extend(moved.ranges.iter().map(|m| shift(m, *offset)));
+        }
+        ranges.into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::collections::BTreeSet;
+
+    fn set(ranges: &[Range<u64>]) -> IntervalSet {
+        ranges.iter().cloned().collect()
+    }
+
+    /// Return the values of `set`, one by one.
+    fn values(set: &IntervalSet) -> BTreeSet<u64> {
+        set.ranges().iter().flat_map(Range::clone).collect()
+    }
+
+    fn small_ranges() -> impl Strategy<Value = Vec<Range<u64>>> {
+        prop::collection::vec((0u64..100, 0u64..20).prop_map(|(s, n)| s..s + n), 0..8)
+    }
+
+    #[test]
+    fn coalesce_ranges() {
+        assert_eq!(
+            coalesce(vec![5..8, 0..2, 2..3, 7..10, 4..4, 12..13]),
+            vec![0..3, 5..10, 12..13]
+        );
+        assert_eq!(set(&[1..3, 3..5]), IntervalSet::from(1..5));
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set(&[0..10, 20..30]);
+        let b = IntervalSet::from(5..25);
+        assert_eq!(a.union(&b), IntervalSet::from(0..30));
+        assert_eq!(a.intersection(&b), set(&[5..10, 20..25]));
+        assert_eq!(a.difference(&b), set(&[0..5, 25..30]));
+        assert_eq!(b.difference(&a), IntervalSet::from(10..20));
+        assert_eq!(
+            a.split_at(25),
+            (set(&[0..10, 20..25]), IntervalSet::from(25..30))
+        );
+        assert_eq!(a.shift(-0), a);
+        assert_eq!(a.shift(5), set(&[5..15, 25..35]));
+        assert_eq!((a.len(), a.min(), a.max()), (20, Some(0), Some(29)));
+        assert!(a.contains(20) && !a.contains(10) && !a.contains(30));
+        assert_eq!(a.to_string(), "{0..10, 20..30}");
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows")]
+    fn shift_below_zero() {
+        IntervalSet::from(3..5).shift(-4);
+    }
+
+    #[test]
+    fn offset_map() {
+        let mut map = OffsetMap::new();
+        map.insert(98..100, 50).unwrap();
+        map.insert(50..98, 52).unwrap();
+        assert_eq!(map.get(49), 49);
+        assert_eq!(map.get(50), 52);
+        assert_eq!(map.get(97), 99);
+        assert_eq!(map.get(99), 51);
+        assert_eq!(map.get(100), 100);
+        assert_eq!(
+            map.map_set(&set(&[45..55, 95..105])),
+            set(&[45..57, 97..105])
+        );
+
+        // The first mapping of a value wins.
+        map.insert(90..110, 0).unwrap();
+        assert_eq!(map.get(99), 51);
+        assert_eq!(map.get(105), 15);
+    }
+
+    #[test]
+    fn offset_map_overflow() {
+        let mut map = OffsetMap::new();
+        let src = u64::MAX - 3..u64::MAX - 1;
+        let err = map.insert(src.clone(), u64::MAX - 1).unwrap_err();
+        assert_eq!(
+            err,
+            OverflowError {
+                src,
+                dst: u64::MAX - 1
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "mapping {}..{} to {} goes past {}",
+                u64::MAX - 3,
+                u64::MAX - 1,
+                u64::MAX - 1,
+                u64::MAX
+            )
+        );
+        // Moving a value down by more than i64::MAX.
+        assert!(map.insert(u64::MAX - 1..u64::MAX, 0).is_err());
+        assert_eq!(map, OffsetMap::new());
+
+        map.insert(u64::MAX - 3..u64::MAX - 1, u64::MAX - 2)
+            .unwrap();
+        map.insert(0..2, i64::MAX as u64).unwrap();
+        assert_eq!(map.get(u64::MAX - 2), u64::MAX - 1);
+        assert_eq!(map.get(1), 1 << 63);
+        assert_eq!(
+            map.map_set(&set(&[1..2, u64::MAX - 2..u64::MAX - 1])),
+            set(&[1 << 63..(1 << 63) + 1, u64::MAX - 1..u64::MAX])
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn set_operations_match_values(a in small_ranges(), b in small_ranges(), at in 0u64..130) {
+            let (a, b) = (set(&a), set(&b));
+            let (va, vb) = (values(&a), values(&b));
+            prop_assert_eq!(values(&a.union(&b)), &va | &vb);
+            prop_assert_eq!(values(&a.intersection(&b)), &va & &vb);
+            prop_assert_eq!(values(&a.difference(&b)), &va - &vb);
+            let (below, above) = a.split_at(at);
+            prop_assert!(values(&below).iter().all(|&v| v < at));
+            prop_assert!(values(&above).iter().all(|&v| v >= at));
+            prop_assert_eq!(below.union(&above), a.clone());
+            prop_assert_eq!(a.len(), va.len() as u64);
+            for v in 0..130 {
+                prop_assert_eq!(a.contains(v), va.contains(&v));
+            }
+        }
+
+        #[test]
+        fn sets_stay_coalesced(a in small_ranges(), b in small_ranges(), offset in 0i64..50) {
+            let (a, b) = (set(&a), set(&b));
+            for s in [a.union(&b), a.intersection(&b), a.difference(&b), a.shift(offset)] {
+                prop_assert!(s.ranges().iter().all(|r| !r.is_empty()));
+                prop_assert!(s.ranges().windows(2).all(|w| w[0].end < w[1].start));
+            }
+            let shifted: BTreeSet<u64> = values(&a).iter().map(|v| v + offset as u64).collect();
+            prop_assert_eq!(values(&a.shift(offset)), shifted);
+        }
+
+        #[test]
+        fn map_set_matches_values(
+            pieces in prop::collection::vec((0u64..100, 0u64..20, 0u64..100), 0..6),
+            a in small_ranges(),
+        ) {
+            let mut map = OffsetMap::new();
+            for (src, len, dst) in pieces {
+                map.insert(src..src + len, dst).unwrap();
+            }
+            let a = set(&a);
+            let image: BTreeSet<u64> = values(&a).iter().map(|&v| map.get(v)).collect();
+            prop_assert_eq!(values(&map.map_set(&a)), image);
+        }
+    }
+}
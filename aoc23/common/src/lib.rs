@@ -0,0 +1,11 @@
+//! Code shared by the days of the `aoc23` workspace.
+
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod math;
+pub mod parse;
+mod solution;
+
+pub use solution::{Solution, SolveError, Solver};
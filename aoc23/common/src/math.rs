@@ -0,0 +1,242 @@
+//! Number theory on unsigned integers: greatest common divisors, least common multiples that
+//! report overflow, modular arithmetic and exact integer square roots.
+
+/// Unsigned integers with a greatest common divisor and a checked least common multiple.
+pub trait Integer: Copy + Eq {
+    /// Return the greatest common divisor, where `gcd(0, 0)` is 0.
+    fn gcd(self, other: Self) -> Self;
+
+    /// Return the least common multiple, or `None` if it overflows. It is 0 if either is 0.
+    fn checked_lcm(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {$(
+        impl Integer for $t {
+            fn gcd(self, other: Self) -> Self {
+                let (mut a, mut b) = (self, other);
+                while b != 0 {
+                    (a, b) = (b, a % b);
+                }
+                a
+            }
+
+            fn checked_lcm(self, other: Self) -> Option<Self> {
+                if self == 0 || other == 0 {
+                    return Some(0);
+                }
+                (self / self.gcd(other)).checked_mul(other)
+            }
+        }
+    )*};
+}
+
+impl_integer!(u8, u16, u32, u64, u128, usize);
+
+/// Return the greatest common divisor of `a` and `b`.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    a.gcd(b)
+}
+
+/// Return the least common multiple of `a` and `b`, or `None` if it overflows.
+pub fn lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    a.checked_lcm(b)
+}
+
+/// Return the least common multiple of `nums`, or `None` if it overflows. It is 1 when `nums` is
+/// empty.
+pub fn lcm_all<T: Integer + From<u8>>(nums: &[T]) -> Option<T> {
+    nums.iter()
+        .try_fold(T::from(1), |acc, &n| acc.checked_lcm(n))
+}
+
+/// Return `(g, x, y)` such that `a * x + b * y = g`, where `g` is the greatest common divisor of
+/// `a` and `b`, non-negative.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// Return the inverse of `a` modulo `m`, if they are coprime.
+pub fn mod_inverse(a: u64, m: u64) -> Option<u64> {
+    let (g, x, _) = extended_gcd(i128::from(a), i128::from(m));
+    (g == 1).then(|| x.rem_euclid(i128::from(m)) as u64)
+}
+
+/// Return the solution of the congruences `x ≡ r (mod m)`, one per `(r, m)`, as `(x, l)` where
+/// `x` is the smallest and `l` is the least common multiple of the moduli, so that every
+/// solution is `x + k * l`. The moduli need not be coprime. Return `None` if the congruences
+/// contradict each other, a modulus is 0 or `l` overflows.
+pub fn crt(congruences: &[(u64, u64)]) -> Option<(u64, u64)> {
+    congruences
+        .iter()
+        .try_fold((0, 1), |(a1, m1): (u64, u64), &(a2, m2)| {
+            if m2 == 0 {
+                return None;
+            }
+            let g = m1.gcd(m2);
+            if a1 % g != a2 % g {
+                return None;
+            }
+            let (m1g, m2g) = (m1 / g, m2 / g);
+            let l = m1g.checked_mul(m2)?;
+            // Solve a1 + m1 * k ≡ a2 (mod m2), that is (m1 / g) * k ≡ (a2 - a1) / g (mod m2 / g).
+            let (a1w, a2w, m2w) = (u128::from(a1), u128::from(a2 % m2), u128::from(m2));
+            let rhs = (a2w + m2w - a1w % m2w) % m2w / u128::from(g);
+            let inv = mod_inverse(m1g % m2g, m2g).unwrap_or(0);
+            let k = (rhs * u128::from(inv) % u128::from(m2g)) as u64;
+            let x = (u128::from(a1) + u128::from(m1) * u128::from(k)) % u128::from(l);
+            Some((x as u64, l))
+        })
+}
+
+/// Return `base` to the power `exp` modulo `modulus`.
+///
+/// # Panics
+///
+/// If `modulus` is 0.
+pub fn modpow(base: u64, mut exp: u64, modulus: u64) -> u64 {
+    assert!(modulus != 0, "modulus must not be 0");
+    let m = u128::from(modulus);
+    let mut base = u128::from(base) % m;
+    let mut result = 1 % m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % m;
+        }
+        base = base * base % m;
+        exp >>= 1;
+    }
+    result as u64
+}
+
+/// Return the largest integer whose square is at most `n`.
+pub fn isqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+    // Newton's method from above: the iterates decrease until they reach the root.
+    let mut x = 1u128 << (n.ilog2() / 2 + 1);
+    loop {
+        let y = (x + n / x) / 2;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcd_and_lcm() {
+        assert_eq!(gcd(12u64, 18), 6);
+        assert_eq!(gcd(0u64, 7), 7);
+        assert_eq!(gcd(0u64, 0), 0);
+        assert_eq!(lcm(2u64, 3), Some(6));
+        assert_eq!(lcm(12u64, 4), Some(12));
+        assert_eq!(lcm(3u64, 7), Some(21));
+        assert_eq!(lcm(0u64, 7), Some(0));
+        assert_eq!(lcm(u64::MAX, u64::MAX - 1), None);
+        assert_eq!(
+            lcm(u128::from(u64::MAX), u128::from(u64::MAX - 1)),
+            Some(u128::from(u64::MAX) * u128::from(u64::MAX - 1))
+        );
+    }
+
+    #[test]
+    fn lcm_of_many() {
+        assert_eq!(lcm_all(&[3u64, 4]), Some(12));
+        assert_eq!(lcm_all(&[3u64, 4, 6, 8]), Some(24));
+        assert_eq!(lcm_all(&[12u64, 18, 3, 4, 9, 6]), Some(36));
+        assert_eq!(lcm_all::<u64>(&[]), Some(1));
+        let primes = [
+            2u64, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53,
+        ];
+        assert_eq!(lcm_all(&primes), None);
+        let primes: Vec<u128> = primes.iter().map(|&p| u128::from(p)).collect();
+        assert_eq!(lcm_all(&primes), Some(primes.iter().product()));
+    }
+
+    #[test]
+    fn bezout() {
+        for a in -30..30 {
+            for b in -30..30 {
+                let (g, x, y) = extended_gcd(a, b);
+                assert_eq!(a * x + b * y, g);
+                assert_eq!(
+                    g as u64,
+                    gcd(a.unsigned_abs() as u64, b.unsigned_abs() as u64)
+                );
+            }
+        }
+        assert_eq!(mod_inverse(3, 7), Some(5));
+        assert_eq!(mod_inverse(4, 8), None);
+    }
+
+    #[test]
+    fn chinese_remainders() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(1, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(crt(&[(5, 0)]), None);
+        assert_eq!(crt(&[]), Some((0, 1)));
+        assert_eq!(
+            crt(&[(u64::MAX - 1, u64::MAX)]),
+            Some((u64::MAX - 1, u64::MAX))
+        );
+        assert_eq!(crt(&[(0, u64::MAX), (0, u64::MAX - 1)]), None);
+
+        // Every pair of small congruences against a search of the solutions.
+        for m1 in 1..12 {
+            for m2 in 1..12 {
+                for a1 in 0..m1 {
+                    for a2 in 0..m2 {
+                        let l = lcm(m1, m2).unwrap();
+                        let x = (0..l).find(|x| x % m1 == a1 && x % m2 == a2);
+                        assert_eq!(crt(&[(a1, m1), (a2, m2)]), x.map(|x| (x, l)));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn powers() {
+        assert_eq!(modpow(2, 10, 1000), 24);
+        assert_eq!(modpow(3, 0, 7), 1);
+        assert_eq!(modpow(3, 0, 1), 0);
+        assert_eq!(modpow(u64::MAX, 2, u64::MAX - 1), 1);
+        for b in 0..20 {
+            for e in 0..10 {
+                assert_eq!(modpow(b, e, 97), b.pow(e as u32) % 97);
+            }
+        }
+    }
+
+    #[test]
+    fn square_roots() {
+        for n in 0..10_000u128 {
+            let r = isqrt(n);
+            assert!(r * r <= n && (r + 1) * (r + 1) > n, "isqrt({})", n);
+        }
+        assert_eq!(isqrt(u128::MAX), u128::from(u64::MAX));
+        let big = u128::from(u64::MAX) * u128::from(u64::MAX);
+        assert_eq!(isqrt(big), u128::from(u64::MAX));
+        assert_eq!(isqrt(big - 1), u128::from(u64::MAX) - 1);
+    }
+}
@@ -0,0 +1,222 @@
+//! Errors of the day parsers, pointing at the offending text of the puzzle input, and small
+//! parsing helpers that return them.
+//!
+//! A parser creates an error from the slice of the input it could not parse, without knowing
+//! where that slice is. [`ParseError::locate`] then finds its line and column in the whole input,
+//! which [`crate::Solver::parse`] does for every day.
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Where in the input a parse error is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    /// Line number, from 1.
+    pub line: usize,
+    /// Column of the first character of the offending text, from 1.
+    pub column: usize,
+    /// The whole line, without its line break.
+    pub snippet: String,
+    /// Number of characters of the offending text on that line, at least 1.
+    pub width: usize,
+}
+
+/// Malformed puzzle input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    message: String,
+    /// Address and length of the offending text, until it is located.
+    span: (usize, usize),
+    location: Option<Location>,
+    file: Option<String>,
+}
+
+impl ParseError {
+    /// Return the error `message` about `span`, which must be a slice of the input for the error
+    /// to be located.
+    pub fn new(span: &str, message: impl fmt::Display) -> Self {
+        ParseError {
+            message: message.to_string(),
+            span: (span.as_ptr() as usize, span.len()),
+            location: None,
+            file: None,
+        }
+    }
+
+    /// Return the error located in `input`, if its text is a slice of it and it was not located
+    /// already.
+    pub fn locate(mut self, input: &str) -> Self {
+        let (addr, len) = self.span;
+        let base = input.as_ptr() as usize;
+        if self.location.is_some() || addr < base || addr + len > base + input.len() {
+            return self;
+        }
+        let offset = addr - base;
+        let start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+        let snippet = input[start..end].trim_end_matches('\r');
+        let column = input[start..offset].chars().count() + 1;
+        let rest = snippet.get(offset - start..).unwrap_or("");
+        let width = rest
+            .chars()
+            .take(input[offset..offset + len].chars().count());
+        self.location = Some(Location {
+            line: input[..offset].matches('\n').count() + 1,
+            column,
+            snippet: snippet.to_string(),
+            width: width.count().max(1),
+        });
+        self
+    }
+
+    /// Return the error with the name of the file it was read from.
+    pub fn with_file(mut self, file: impl fmt::Display) -> Self {
+        self.file = Some(file.to_string());
+        self
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn location(&self) -> Option<&Location> {
+        self.location.as_ref()
+    }
+
+    pub fn file(&self) -> Option<&str> {
+        self.file.as_deref()
+    }
+
+    /// Return the first line of the error, `file:line:column: message`, without the underlined
+    /// snippet.
+    pub fn summary(&self) -> String {
+        match (&self.file, &self.location) {
+            (Some(file), Some(loc)) => {
+                format!("{}:{}:{}: {}", file, loc.line, loc.column, self.message)
+            }
+            (None, Some(loc)) => {
+                format!("line {}, column {}: {}", loc.line, loc.column, self.message)
+            }
+            (Some(file), None) => format!("{}: {}", file, self.message),
+            (None, None) => self.message.clone(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    /// Write `file:line:column: message`, followed by the line with the offending text
+    /// underlined.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.summary())?;
+        let Some(loc) = &self.location else {
+            return Ok(());
+        };
+        writeln!(f)?;
+        let margin = " ".repeat(loc.line.to_string().len());
+        writeln!(f, "{} |", margin)?;
+        writeln!(f, "{} | {}", loc.line, loc.snippet)?;
+        write!(
+            f,
+            "{} | {}{}",
+            margin,
+            " ".repeat(loc.column - 1),
+            "^".repeat(loc.width)
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parse `s` as a number.
+pub fn number<T: FromStr>(s: &str) -> Result<T, ParseError> {
+    s.parse()
+        .map_err(|_| ParseError::new(s, format!("expected a number, found {:?}", s)))
+}
+
+/// Parse the numbers of `s`, separated by whitespace.
+pub fn numbers<T: FromStr>(s: &str) -> Result<Vec<T>, ParseError> {
+    s.split_whitespace().map(number).collect()
+}
+
+/// Split `s` around the first `delimiter`.
+pub fn split_once<'a>(s: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(delimiter)
+        .ok_or_else(|| ParseError::new(s, format!("expected {:?}", delimiter)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "Game 1: 3 blue\nGame 2: x red\n";
+
+    #[test]
+    fn locate_in_input() {
+        let line = INPUT.lines().nth(1).unwrap();
+        let err = number::<u64>(&line[8..9]).unwrap_err().locate(INPUT);
+        assert_eq!(
+            err.location(),
+            Some(&Location {
+                line: 2,
+                column: 9,
+                snippet: "Game 2: x red".to_string(),
+                width: 1,
+            })
+        );
+        let err = err.with_file("day2/data/input.txt");
+        assert_eq!(
+            err.summary(),
+            "day2/data/input.txt:2:9: expected a number, found \"x\""
+        );
+        assert_eq!(
+            err.to_string(),
+            "day2/data/input.txt:2:9: expected a number, found \"x\"
+  |
+2 | Game 2: x red
+  |         ^"
+        );
+    }
+
+    #[test]
+    fn unlocated() {
+        let err = ParseError::new("elsewhere", "bad").locate(INPUT);
+        assert_eq!(err.location(), None);
+        assert_eq!(err.to_string(), "bad");
+        assert_eq!(err.with_file("f.txt").to_string(), "f.txt: bad");
+    }
+
+    #[test]
+    fn spans() {
+        let input = "ab\r\ncdé\n";
+        // Empty text at the end of a line gets one caret after it.
+        let err = ParseError::new(&input[2..2], "missing").locate(input);
+        let loc = err.location().unwrap();
+        assert_eq!((loc.line, loc.column, loc.width), (1, 3, 1));
+        assert_eq!(loc.snippet, "ab");
+        // Columns count characters, and spans are cut at the end of their line.
+        let err = ParseError::new(&input[5..], "rest").locate(input);
+        let loc = err.location().unwrap();
+        assert_eq!((loc.line, loc.column, loc.width), (2, 2, 2));
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 2: rest\n  |\n2 | cdé\n  |  ^^"
+        );
+        // Locating again keeps the first location.
+        assert_eq!(err.clone().locate("cdé"), err);
+    }
+
+    #[test]
+    fn helpers() {
+        assert_eq!(number::<i64>("-12"), Ok(-12));
+        assert_eq!(numbers::<u8>(" 1  2 3"), Ok(vec![1, 2, 3]));
+        assert!(numbers::<u8>("1 300").is_err());
+        assert_eq!(split_once("a: b", ":"), Ok(("a", " b")));
+        assert_eq!(
+            split_once("a b", ":").unwrap_err().message(),
+            "expected \":\""
+        );
+    }
+}
@@ -0,0 +1,89 @@
+use crate::parse::ParseError;
+use std::any::Any;
+use std::error::Error;
+use std::fmt::{self, Display};
+
+/// The solution of a day's puzzle: parse the input once, then answer both parts from it.
+pub trait Solution {
+    /// Day of the puzzle, from 1 to 25.
+    const DAY: u8;
+
+    /// Parsed puzzle input shared by both parts.
+    type Input: 'static;
+
+    /// Parse the puzzle input, or return an error about its first malformed part.
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Return the answer of part 1, or why the input has none.
+    fn part1(input: &Self::Input) -> Result<impl Display, SolveError>;
+
+    /// Return the answer of part 2, or why the input has none.
+    fn part2(input: &Self::Input) -> Result<impl Display, SolveError>;
+}
+
+/// Why a part has no answer for an input that parses, such as a start node that only the other
+/// part needs, or a path that never reaches its end.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveError {
+    message: String,
+}
+
+impl SolveError {
+    pub fn new(message: impl Into<String>) -> Self {
+        SolveError {
+            message: message.into(),
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for SolveError {}
+
+/// Object safe view of a [`Solution`], so that days with different input types can be stored
+/// together and driven generically.
+pub trait Solver: Sync {
+    fn day(&self) -> u8;
+
+    /// Parse the input into an opaque value to pass to [`Solver::solve`], or return an error
+    /// located in `input`.
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError>;
+
+    /// Return the answer of `part` for the `parsed` input or why it has none, or None if `part`
+    /// is not 1 or 2.
+    ///
+    /// Panics if `parsed` was not returned by this solver's [`Solver::parse`].
+    fn solve(&self, part: u8, parsed: &dyn Any) -> Option<Result<String, SolveError>>;
+}
+
+impl<S: Solution + Sync> Solver for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        match S::parse(input) {
+            Ok(parsed) => Ok(Box::new(parsed)),
+            Err(e) => Err(e.locate(input)),
+        }
+    }
+
+    fn solve(&self, part: u8, parsed: &dyn Any) -> Option<Result<String, SolveError>> {
+        let input = parsed
+            .downcast_ref::<S::Input>()
+            .expect("parsed input of another day");
+        match part {
+            1 => Some(S::part1(input).map(|answer| answer.to_string())),
+            2 => Some(S::part2(input).map(|answer| answer.to_string())),
+            _ => None,
+        }
+    }
+}
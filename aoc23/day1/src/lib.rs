@@ -0,0 +1,186 @@
+pub mod scanner;
+pub mod validation;
+pub mod vocabulary;
+
+use common::parse::ParseError;
+use common::{Solution, SolveError};
+use scanner::{Digits, Scanner};
+use std::fmt::Display;
+use validation::{Policy, Reason, Report};
+use vocabulary::Vocabulary;
+
+/// Parse the calibration document into its lines. The lines without any digit are left to the
+/// totals, which handle them according to a [`Policy`].
+pub fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    Ok(input.lines().map(str::to_string).collect())
+}
+
+/// Return the sum of the calibration values made of the first and last ascii digit of each line,
+/// or an error with the lines that have none.
+pub fn part1(lines: &[String]) -> Result<i64, SolveError> {
+    first_last_digit_total(lines, Digits::Ascii, Policy::Fail)
+        .map_err(|report| digitless_error("ascii digit", &report))
+}
+
+/// Return the sum of the calibration values made of the first and last, possibly spelled out in
+/// English, digit of each line, or an error with the lines that have none.
+pub fn part2(lines: &[String]) -> Result<i64, SolveError> {
+    first_last_spelled_total(lines, Digits::Ascii, &Vocabulary::puzzle(), Policy::Fail)
+        .map_err(|report| digitless_error("digit", &report))
+}
+
+/// Return the error of a part about the lines of `report`, which have no `digit`.
+fn digitless_error(digit: &str, report: &Report) -> SolveError {
+    let lines: Vec<String> = report.lines.iter().map(|(n, _)| n.to_string()).collect();
+    SolveError::new(format!("no {} on lines {}", digit, lines.join(", ")))
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, SolveError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, SolveError> {
+        part2(input)
+    }
+}
+
+/// Return the sum of the calibration values of `lines`, made of their first and last digit among
+/// `digits`, with the lines without any handled according to `policy`.
+pub fn first_last_digit_total(
+    lines: &[String],
+    digits: Digits,
+    policy: Policy,
+) -> Result<i64, Report> {
+    calibration_total(lines, &Scanner::digits(digits), policy)
+}
+
+/// Return the sum of the calibration values of `lines`, made of their first and last digit, among
+/// `digits` or a word of `vocabulary`, with the lines without any handled according to `policy`.
+pub fn first_last_spelled_total(
+    lines: &[String],
+    digits: Digits,
+    vocabulary: &Vocabulary,
+    policy: Policy,
+) -> Result<i64, Report> {
+    calibration_total(lines, &Scanner::spelled(digits, vocabulary), policy)
+}
+
+/// Return the sum of the calibration values of `lines`, made of the first and last digit found by
+/// `scanner`, or with [`Policy::Fail`] the report of the lines without any.
+fn calibration_total(lines: &[String], scanner: &Scanner, policy: Policy) -> Result<i64, Report> {
+    let mut total = 0;
+    let mut report = Report::default();
+    for (line, n) in lines.iter().zip(1..) {
+        match (scanner.first_last(line), policy) {
+            (Some((first, last)), _) => total += i64::from(first * 10 + last),
+            (None, Policy::Fail) => report.lines.push((n, Reason::of(line))),
+            // A calibration value of 0 adds nothing, like a skipped line.
+            (None, Policy::Skip | Policy::Zero) => {}
+        }
+    }
+    if report.is_empty() {
+        Ok(total)
+    } else {
+        Err(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+
+    #[test]
+    fn calibration_totals() {
+        let lines = parse("1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n").unwrap();
+        assert_eq!(part1(&lines), Ok(142));
+        assert_eq!(
+            first_last_digit_total(&lines, Digits::Ascii, Policy::Fail),
+            Ok(142)
+        );
+        let lines: Vec<String> = ["🎅１x7", "٤🎄"].map(String::from).into();
+        assert_eq!(
+            first_last_digit_total(&lines, Digits::Unicode, Policy::Fail),
+            Ok(17 + 44)
+        );
+        let lines = parse(
+            "two1nine\neightwothree\nabcone2threexyz\nxtwone3four\n\
+             4nineeightseven2\nzoneight234\n7pqrstsixteen\n",
+        )
+        .unwrap();
+        assert_eq!(
+            first_last_spelled_total(&lines, Digits::Ascii, &Vocabulary::puzzle(), Policy::Fail),
+            Ok(281)
+        );
+        let lines: Vec<String> = ["dreizehn", "NEUN8null", "vierundzwanzig"]
+            .map(String::from)
+            .into();
+        let german = Vocabulary::language("german").unwrap().ignoring_case();
+        assert_eq!(
+            first_last_spelled_total(&lines, Digits::Ascii, &german, Policy::Fail),
+            Ok(33 + 90 + 44)
+        );
+    }
+
+    #[test]
+    fn digitless_lines() {
+        let lines: Vec<String> = ["1abc2", "abc", "", "two", "3"].map(String::from).into();
+        let report = first_last_digit_total(&lines, Digits::Ascii, Policy::Fail).unwrap_err();
+        assert_eq!(
+            report.lines,
+            [
+                (2, Reason::NoDigit),
+                (3, Reason::Empty),
+                (4, Reason::NoDigit)
+            ]
+        );
+        for policy in [Policy::Skip, Policy::Zero] {
+            assert_eq!(
+                first_last_digit_total(&lines, Digits::Ascii, policy),
+                Ok(45)
+            );
+        }
+        let puzzle = Vocabulary::puzzle();
+        let report = first_last_spelled_total(&lines, Digits::Ascii, &puzzle, Policy::Fail);
+        assert_eq!(
+            report.unwrap_err().to_string(),
+            "line 2: no digit\nline 3: empty line"
+        );
+    }
+
+    #[test]
+    fn digitless_inputs() {
+        let lines = parse("1abc2\nabc\n\ntwo\n3\n").unwrap();
+        let err = part1(&lines).unwrap_err();
+        assert_eq!(err.message(), "no ascii digit on lines 2, 3, 4");
+        let err = part2(&lines).unwrap_err();
+        assert_eq!(err.message(), "no digit on lines 2, 3");
+
+        let lines = parse("1abc2\ntwo\n3\n").unwrap();
+        assert_eq!(part2(&lines), Ok(12 + 22 + 33));
+    }
+
+    #[test]
+    fn consumed_digits_rule() {
+        // Calibration values where the letters of a spelled digit are not read again.
+        let scanner = Scanner::spelled(Digits::Ascii, &Vocabulary::puzzle());
+        let value = |line: &str| {
+            let mut tokens = scanner.tokens(line, scanner::Overlap::Consumed);
+            let first = tokens.next().unwrap().digit;
+            i64::from(first * 10 + tokens.last().map_or(first, |t| t.digit))
+        };
+        assert_eq!(value("eightwo"), 88);
+        assert_eq!(value("twone3oneight"), 21);
+        assert_eq!(value("7pqrstsixteen"), 76);
+    }
+}
@@ -0,0 +1,492 @@
+//! Search of the digits of a line in a single pass, with an Aho–Corasick automaton over the
+//! vocabulary of digits, such as `7` and `seven`: only the first and last of them, or every one as
+//! a [`Token`].
+//!
+//! The automaton reads bytes, but its patterns are whole UTF-8 characters, so a match never
+//! starts or ends inside a character and lines of any text are scanned without decoding them.
+
+use crate::vocabulary::Vocabulary;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+use std::ops::Range;
+
+/// Deterministic automaton that finds every occurrence of a set of patterns, each standing for a
+/// digit, in one pass over the bytes of a line.
+#[derive(Debug, Clone)]
+pub struct Scanner {
+    /// Next state for each state and byte, with the failure links folded in.
+    next: Vec<[u32; 256]>,
+    /// Patterns that end in each state, as their length in bytes, their digit and their source,
+    /// longest first.
+    outputs: Vec<Vec<(usize, u8, Source)>>,
+    /// Length in bytes of the longest pattern.
+    longest: usize,
+}
+
+const ROOT: u32 = 0;
+
+impl Scanner {
+    /// Return the scanner of `patterns`, each a word, the digit it stands for and whether it is
+    /// numeric or spelled. When two patterns are the same word, the first one counts.
+    pub fn new<'a>(patterns: impl IntoIterator<Item = (&'a str, u8, Source)>) -> Self {
+        // Trie of the patterns, where 0 is the root and stands for a missing edge.
+        let mut next = vec![[ROOT; 256]];
+        let mut outputs: Vec<Vec<(usize, u8, Source)>> = vec![vec![]];
+        let mut longest = 0;
+        for (word, digit, source) in patterns {
+            let mut state = ROOT as usize;
+            for &b in word.as_bytes() {
+                if next[state][b as usize] == ROOT {
+                    next[state][b as usize] = next.len() as u32;
+                    next.push([ROOT; 256]);
+                    outputs.push(vec![]);
+                }
+                state = next[state][b as usize] as usize;
+            }
+            if outputs[state].is_empty() && !word.is_empty() {
+                outputs[state].push((word.len(), digit, source));
+                longest = longest.max(word.len());
+            }
+        }
+
+        // Breadth first, so that the failure state of each state, the state of its longest
+        // proper suffix in the trie, is complete before the state itself.
+        let mut fail = vec![ROOT; next.len()];
+        let mut queue: VecDeque<usize> = next[ROOT as usize]
+            .iter()
+            .filter(|&&s| s != ROOT)
+            .map(|&s| s as usize)
+            .collect();
+        while let Some(state) = queue.pop_front() {
+            let suffix_outputs = outputs[fail[state] as usize].clone();
+            outputs[state].extend(suffix_outputs);
+            let fail_row = next[fail[state] as usize];
+            for (child, via_fail) in next[state].iter_mut().zip(fail_row) {
+                if *child == ROOT {
+                    *child = via_fail;
+                } else {
+                    fail[*child as usize] = via_fail;
+                    queue.push_back(*child as usize);
+                }
+            }
+        }
+        Scanner {
+            next,
+            outputs,
+            longest,
+        }
+    }
+
+    /// Return the scanner of `digits`.
+    pub fn digits(digits: Digits) -> Self {
+        let patterns = digits.patterns();
+        Self::new(
+            patterns
+                .iter()
+                .map(|(w, d)| (w.as_str(), *d, Source::Numeric)),
+        )
+    }
+
+    /// Return the scanner of `digits` and of the words of `vocabulary`.
+    pub fn spelled(digits: Digits, vocabulary: &Vocabulary) -> Self {
+        let mut patterns: Vec<(String, u8, Source)> = digits
+            .patterns()
+            .into_iter()
+            .map(|(c, d)| (c, d, Source::Numeric))
+            .collect();
+        for (word, digit) in vocabulary.words() {
+            if vocabulary.ignores_case() {
+                let variants = case_variants(word).into_iter();
+                patterns.extend(variants.map(|v| (v, *digit, Source::Spelled)));
+            } else {
+                patterns.push((word.clone(), *digit, Source::Spelled));
+            }
+        }
+        let mut scanner = Self::new(patterns.iter().map(|(w, d, s)| (w.as_str(), *d, *s)));
+        if vocabulary.ignores_case() {
+            // The patterns have no ascii capitals, so reading one as its lower case reads the
+            // line as if lowercased.
+            for row in &mut scanner.next {
+                for upper in b'A'..=b'Z' {
+                    row[upper as usize] = row[upper.to_ascii_lowercase() as usize];
+                }
+            }
+        }
+        scanner
+    }
+
+    /// Return the first and last digit of `line`, or None if it has none. Of two digits starting
+    /// at the same byte, the longer counts.
+    pub fn first_last(&self, line: &str) -> Option<(u8, u8)> {
+        // Start, length and digit of the first and of the last match so far.
+        let mut first: Option<(usize, usize, u8)> = None;
+        let mut last: Option<(usize, usize, u8)> = None;
+        let mut state = ROOT as usize;
+        for (i, &b) in line.as_bytes().iter().enumerate() {
+            state = self.next[state][b as usize] as usize;
+            for &(len, digit, _) in &self.outputs[state] {
+                let start = i + 1 - len;
+                if first.is_none_or(|(s, l, _)| start < s || (start == s && len > l)) {
+                    first = Some((start, len, digit));
+                }
+                if last.is_none_or(|(s, l, _)| start > s || (start == s && len > l)) {
+                    last = Some((start, len, digit));
+                }
+            }
+        }
+        Some((first?.2, last?.2))
+    }
+
+    /// Return the digits of `line` in the order they start, longest first at the same start,
+    /// keeping those that overlap earlier ones or not according to `overlap`.
+    pub fn tokens<'a>(&'a self, line: &'a str, overlap: Overlap) -> Tokens<'a> {
+        Tokens {
+            scanner: self,
+            bytes: line.as_bytes(),
+            read: 0,
+            state: ROOT as usize,
+            pending: BinaryHeap::new(),
+            overlap,
+            consumed: 0,
+        }
+    }
+}
+
+/// Whether a digit was written with a digit character or spelled out as a word.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Source {
+    Numeric,
+    Spelled,
+}
+
+/// A digit found in a line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    pub digit: u8,
+    /// Byte range of the digit in the line.
+    pub span: Range<usize>,
+    pub source: Source,
+}
+
+/// Which digits sharing bytes with an earlier one are kept, as `two` in `eightwo`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Overlap {
+    /// Keep every digit, as the puzzle does.
+    #[default]
+    Allowed,
+    /// Read the line from left to right, so that the bytes of a digit are not read again: keep a
+    /// digit only if it starts after the end of the last kept one.
+    Consumed,
+}
+
+/// Iterator over the digits of a line, from [`Scanner::tokens`].
+#[derive(Debug, Clone)]
+pub struct Tokens<'a> {
+    scanner: &'a Scanner,
+    bytes: &'a [u8],
+    /// Number of bytes read by the automaton.
+    read: usize,
+    state: usize,
+    /// Digits found but not returned yet, as their start, length, digit and source, the first to
+    /// return on top.
+    pending: BinaryHeap<(Reverse<usize>, usize, u8, Source)>,
+    overlap: Overlap,
+    /// End of the last returned digit.
+    consumed: usize,
+}
+
+impl Iterator for Tokens<'_> {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Token> {
+        loop {
+            // A digit is complete once the automaton is past the end of the longest pattern
+            // starting with it, as no digit found later can start before it.
+            let done = self.read == self.bytes.len();
+            match self.pending.peek() {
+                Some(&(Reverse(start), ..))
+                    if done || start + self.scanner.longest <= self.read =>
+                {
+                    let (Reverse(start), len, digit, source) = self.pending.pop().unwrap();
+                    if self.overlap == Overlap::Consumed && start < self.consumed {
+                        continue;
+                    }
+                    self.consumed = start + len;
+                    return Some(Token {
+                        digit,
+                        span: start..start + len,
+                        source,
+                    });
+                }
+                _ if done => return None,
+                _ => {}
+            }
+            let b = self.bytes[self.read];
+            self.read += 1;
+            self.state = self.scanner.next[self.state][b as usize] as usize;
+            for &(len, digit, source) in &self.scanner.outputs[self.state] {
+                self.pending
+                    .push((Reverse(self.read - len), len, digit, source));
+            }
+        }
+    }
+}
+
+/// Characters read as numeric digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Digits {
+    /// `0` to `9`, as in the puzzle.
+    #[default]
+    Ascii,
+    /// The ascii digits and the decimal digits of other scripts, such as `１` or `٣`.
+    Unicode,
+}
+
+impl Digits {
+    /// Return every digit character, as a pattern with its value.
+    fn patterns(self) -> Vec<(String, u8)> {
+        let zeros = match self {
+            Digits::Ascii => &UNICODE_ZEROS[..1],
+            Digits::Unicode => &UNICODE_ZEROS[..],
+        };
+        zeros
+            .iter()
+            .flat_map(|&zero| (0..10).map(move |d| (zero, d)))
+            .map(|(zero, d)| {
+                let c = char::from_u32(zero as u32 + d as u32).unwrap();
+                (c.to_string(), d)
+            })
+            .collect()
+    }
+}
+
+/// Zero of the scripts whose ten decimal digits follow each other, ascii first.
+const UNICODE_ZEROS: [char; 21] = [
+    '0', '٠', '۰', '߀', '०', '০', '੦', '૦', '୦', '௦', '౦', '೦', '൦', '෦', '๐', '໐', '༠', '၀', '០',
+    '᠐', '０',
+];
+
+/// Return the spellings of `word` in every case, with its ascii letters in lower case, which the
+/// scanner matches in any case by itself.
+fn case_variants(word: &str) -> Vec<String> {
+    let mut variants = vec![String::new()];
+    for c in word.chars() {
+        let lower = c.to_lowercase().to_string().to_ascii_lowercase();
+        let upper = c.to_uppercase().to_string().to_ascii_lowercase();
+        if c.is_ascii() || lower == upper {
+            variants.iter_mut().for_each(|v| v.push_str(&lower));
+        } else {
+            variants = variants
+                .into_iter()
+                .flat_map(|v| [v.clone() + &lower, v + &upper])
+                .collect();
+        }
+    }
+    variants
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Return the first and last digit of `line` by searching every pattern of `digits` and of
+    /// the puzzle separately.
+    fn naive(line: &str, digits: Digits) -> Option<(u8, u8)> {
+        let mut patterns = digits.patterns();
+        patterns.extend(Vocabulary::puzzle().words().iter().cloned());
+        let found: Vec<(usize, u8)> = patterns
+            .iter()
+            .flat_map(|(word, digit)| line.match_indices(word).map(move |(i, _)| (i, *digit)))
+            .collect();
+        let first = found.iter().min_by_key(|(i, _)| *i)?.1;
+        let last = found.iter().max_by_key(|(i, _)| *i)?.1;
+        Some((first, last))
+    }
+
+    #[test]
+    fn ascii_digits() {
+        let scanner = Scanner::digits(Digits::Ascii);
+        assert_eq!(scanner.first_last("1abc2"), Some((1, 2)));
+        assert_eq!(scanner.first_last("pqr3stu8vwx"), Some((3, 8)));
+        assert_eq!(scanner.first_last("a1b2c3d4e5f"), Some((1, 5)));
+        assert_eq!(scanner.first_last("treb7uchet"), Some((7, 7)));
+        assert_eq!(scanner.first_last("two1nine"), Some((1, 1)));
+        assert_eq!(scanner.first_last("abc"), None);
+        assert_eq!(scanner.first_last(""), None);
+    }
+
+    #[test]
+    fn spelled_digits() {
+        let scanner = Scanner::spelled(Digits::Ascii, &Vocabulary::puzzle());
+        assert_eq!(scanner.first_last("two1nine"), Some((2, 9)));
+        assert_eq!(scanner.first_last("eightwothree"), Some((8, 3)));
+        assert_eq!(scanner.first_last("abcone2threexyz"), Some((1, 3)));
+        assert_eq!(scanner.first_last("xtwone3four"), Some((2, 4)));
+        assert_eq!(scanner.first_last("4nineeightseven2"), Some((4, 2)));
+        assert_eq!(scanner.first_last("zoneight234"), Some((1, 4)));
+        assert_eq!(scanner.first_last("7pqrstsixteen"), Some((7, 6)));
+        assert_eq!(scanner.first_last("abcdefgone"), Some((1, 1)));
+        // Overlapping words, and a word that starts inside a failed one.
+        assert_eq!(scanner.first_last("twone"), Some((2, 1)));
+        assert_eq!(scanner.first_last("sevenine"), Some((7, 9)));
+        assert_eq!(scanner.first_last("ninineight"), Some((9, 8)));
+        assert_eq!(scanner.first_last("thrthree"), Some((3, 3)));
+    }
+
+    #[test]
+    fn longest_at_same_start() {
+        let patterns = [("ab", 1), ("abc", 2), ("bc", 3), ("c", 4)];
+        let scanner = Scanner::new(patterns.map(|(w, d)| (w, d, Source::Spelled)));
+        assert_eq!(scanner.first_last("abc"), Some((2, 4)));
+        assert_eq!(scanner.first_last("xab"), Some((1, 1)));
+        assert_eq!(scanner.first_last("bcx"), Some((3, 4)));
+    }
+
+    #[test]
+    fn vocabularies() {
+        let german = Vocabulary::language("german").unwrap();
+        let scanner = Scanner::spelled(Digits::Ascii, &german);
+        assert_eq!(scanner.first_last("xnullfünfzig"), Some((0, 5)));
+        assert_eq!(scanner.first_last("FÜNFsieben"), Some((7, 7)));
+        let mixed = Vocabulary::language("french")
+            .unwrap()
+            .union(&Vocabulary::language("romanian").unwrap());
+        let scanner = Scanner::spelled(Digits::Ascii, &mixed);
+        assert_eq!(scanner.first_last("zérounu"), Some((0, 1)));
+        assert_eq!(scanner.first_last("nouăxșapte3quatre"), Some((9, 4)));
+    }
+
+    #[test]
+    fn ignoring_case() {
+        let scanner = Scanner::spelled(Digits::Ascii, &Vocabulary::puzzle().ignoring_case());
+        assert_eq!(scanner.first_last("SevenTWOnE"), Some((7, 1)));
+        let german = Vocabulary::language("german").unwrap().ignoring_case();
+        let scanner = Scanner::spelled(Digits::Ascii, &german);
+        assert_eq!(scanner.first_last("FÜNFzigDrei"), Some((5, 3)));
+        assert_eq!(scanner.first_last("xFünF"), Some((5, 5)));
+        assert_eq!(case_variants("Fünf"), ["fünf", "fÜnf"]);
+    }
+
+    #[test]
+    fn multibyte_lines() {
+        let scanner = Scanner::spelled(Digits::Ascii, &Vocabulary::puzzle());
+        assert_eq!(scanner.first_last("🎄two🦌🦌3🎁"), Some((2, 3)));
+        assert_eq!(scanner.first_last("👨‍👩‍👧‍👦ｏｎｅ１nine✨"), Some((9, 9)));
+        assert_eq!(scanner.first_last("é8ß"), Some((8, 8)));
+        assert_eq!(scanner.first_last("🙂🙃"), None);
+        // A digit in a multibyte character is not read in ascii mode.
+        assert_eq!(scanner.first_last("４２"), None);
+    }
+
+    #[test]
+    fn unicode_digits() {
+        let scanner = Scanner::digits(Digits::Unicode);
+        assert_eq!(scanner.first_last("４２"), Some((4, 2)));
+        assert_eq!(scanner.first_last("🎂٣abc۷🎉"), Some((3, 7)));
+        assert_eq!(scanner.first_last("x५y9"), Some((5, 9)));
+        assert_eq!(scanner.first_last("Ⅻ½²"), None);
+        let scanner = Scanner::spelled(Digits::Unicode, &Vocabulary::puzzle());
+        assert_eq!(scanner.first_last("٠one🚀"), Some((0, 1)));
+        for (c, _) in Digits::Unicode.patterns() {
+            assert!(c.chars().all(char::is_numeric), "{:?} is not a digit", c);
+        }
+    }
+
+    /// Return the digit and start of each token of `line`.
+    fn digits_at(scanner: &Scanner, line: &str, overlap: Overlap) -> Vec<(u8, usize)> {
+        let tokens = scanner.tokens(line, overlap);
+        tokens.map(|t| (t.digit, t.span.start)).collect()
+    }
+
+    #[test]
+    fn tokens() {
+        let scanner = Scanner::spelled(Digits::Ascii, &Vocabulary::puzzle());
+        let tokens: Vec<Token> = scanner.tokens("a7eightwo🎄", Overlap::Allowed).collect();
+        assert_eq!(
+            tokens,
+            [
+                Token {
+                    digit: 7,
+                    span: 1..2,
+                    source: Source::Numeric
+                },
+                Token {
+                    digit: 8,
+                    span: 2..7,
+                    source: Source::Spelled
+                },
+                Token {
+                    digit: 2,
+                    span: 6..9,
+                    source: Source::Spelled
+                },
+            ]
+        );
+        assert_eq!(scanner.tokens("xyz", Overlap::Allowed).count(), 0);
+        assert_eq!(
+            digits_at(&scanner, "twoneight", Overlap::Allowed),
+            [(2, 0), (1, 2), (8, 4)]
+        );
+        assert_eq!(
+            digits_at(&scanner, "twoneight", Overlap::Consumed),
+            [(2, 0), (8, 4)]
+        );
+        assert_eq!(
+            digits_at(&scanner, "oneightwo3", Overlap::Consumed),
+            [(1, 0), (2, 6), (3, 9)]
+        );
+    }
+
+    #[test]
+    fn tokens_longest_first() {
+        let patterns = [("ab", 1), ("abc", 2), ("bcd", 3), ("c", 4)];
+        let scanner = Scanner::new(patterns.map(|(w, d)| (w, d, Source::Spelled)));
+        assert_eq!(
+            digits_at(&scanner, "abcd", Overlap::Allowed),
+            [(2, 0), (1, 0), (3, 1), (4, 2)]
+        );
+        assert_eq!(digits_at(&scanner, "abcd", Overlap::Consumed), [(2, 0)]);
+    }
+
+    proptest! {
+        #[test]
+        fn same_as_naive(
+            line in "(one|two|three|four|five|six|seven|eight|nine|[0-9]|[a-z]){0,12}"
+        ) {
+            let scanner = Scanner::spelled(Digits::Ascii, &Vocabulary::puzzle());
+            prop_assert_eq!(scanner.first_last(&line), naive(&line, Digits::Ascii));
+        }
+
+        #[test]
+        fn tokens_in_order(
+            line in "(one|two|three|four|five|six|seven|eight|nine|[0-9]|[a-z]|🎄){0,12}"
+        ) {
+            let scanner = Scanner::spelled(Digits::Ascii, &Vocabulary::puzzle());
+            let all: Vec<Token> = scanner.tokens(&line, Overlap::Allowed).collect();
+            let mut found = all.clone();
+            found.sort_by_key(|t| (t.span.start, Reverse(t.span.end)));
+            prop_assert_eq!(&all, &found);
+            let words = Vocabulary::puzzle();
+            let spelled = words.words().iter().map(|(w, _)| line.matches(w.as_str()).count());
+            let numeric = line.matches(|c: char| c.is_ascii_digit()).count();
+            prop_assert_eq!(all.len(), numeric + spelled.sum::<usize>());
+            // The first and last digits, the longest at the same start, are those of first_last.
+            let last = all.iter().find(|t| Some(t.span.start) == all.last().map(|l| l.span.start));
+            let ends = all.first().zip(last).map(|(f, l)| (f.digit, l.digit));
+            prop_assert_eq!(ends, scanner.first_last(&line));
+            let consumed: Vec<Token> = scanner.tokens(&line, Overlap::Consumed).collect();
+            prop_assert!(consumed.windows(2).all(|w| w[0].span.end <= w[1].span.start));
+            prop_assert!(consumed.iter().all(|t| all.contains(t)));
+            prop_assert_eq!(consumed.first(), all.first());
+        }
+
+        #[test]
+        fn any_text_same_as_naive(line in "(one|nine|\\PC|[0-9]|[०-९]|[０-９]){0,12}") {
+            for digits in [Digits::Ascii, Digits::Unicode] {
+                let scanner = Scanner::spelled(digits, &Vocabulary::puzzle());
+                prop_assert_eq!(scanner.first_last(&line), naive(&line, digits));
+            }
+        }
+    }
+}
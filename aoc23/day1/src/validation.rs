@@ -0,0 +1,132 @@
+//! Lines of a calibration document that have no calibration value, and what the totals do with
+//! them.
+
+use crate::scanner::Scanner;
+use std::error::Error;
+use std::fmt;
+
+/// What the totals do with a line without any digit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Policy {
+    /// Return the report of every such line instead of a total.
+    #[default]
+    Fail,
+    /// Leave the line out of the total.
+    Skip,
+    /// Count the line as a calibration value of 0.
+    Zero,
+}
+
+impl Policy {
+    /// Return the policy named `name` (`fail`, `skip` or `zero`), ignoring case.
+    pub fn named(name: &str) -> Option<Self> {
+        [Self::Fail, Self::Skip, Self::Zero]
+            .into_iter()
+            .find(|policy| policy.to_string().eq_ignore_ascii_case(name))
+    }
+}
+
+impl fmt::Display for Policy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Fail => write!(f, "fail"),
+            Self::Skip => write!(f, "skip"),
+            Self::Zero => write!(f, "zero"),
+        }
+    }
+}
+
+/// Why a line has no calibration value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reason {
+    Empty,
+    NoDigit,
+}
+
+impl Reason {
+    /// Return the reason why `line`, in which no digit was found, has no calibration value.
+    pub(crate) fn of(line: &str) -> Self {
+        if line.trim().is_empty() {
+            Self::Empty
+        } else {
+            Self::NoDigit
+        }
+    }
+}
+
+impl fmt::Display for Reason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "empty line"),
+            Self::NoDigit => write!(f, "no digit"),
+        }
+    }
+}
+
+/// Lines without any digit, by line number from 1.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Report {
+    pub lines: Vec<(usize, Reason)>,
+}
+
+impl Report {
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+}
+
+impl fmt::Display for Report {
+    /// Write one line per offending line, `line N: reason`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, (line, reason)) in self.lines.iter().enumerate() {
+            let sep = if i == 0 { "" } else { "\n" };
+            write!(f, "{}line {}: {}", sep, line, reason)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for Report {}
+
+/// Return the report of the lines in which `scanner` finds no digit.
+pub fn validate(lines: &[String], scanner: &Scanner) -> Report {
+    let lines = lines
+        .iter()
+        .zip(1..)
+        .filter(|(line, _)| scanner.first_last(line).is_none())
+        .map(|(line, n)| (n, Reason::of(line)))
+        .collect();
+    Report { lines }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::Digits;
+
+    #[test]
+    fn report() {
+        let lines: Vec<String> = ["a1", "", "one", "🎄", "2b"].map(String::from).into();
+        let report = validate(&lines, &Scanner::digits(Digits::Ascii));
+        assert_eq!(
+            report.lines,
+            [
+                (2, Reason::Empty),
+                (3, Reason::NoDigit),
+                (4, Reason::NoDigit)
+            ]
+        );
+        assert_eq!(
+            report.to_string(),
+            "line 2: empty line\nline 3: no digit\nline 4: no digit"
+        );
+        assert!(validate(&lines[..1], &Scanner::digits(Digits::Ascii)).is_empty());
+    }
+
+    #[test]
+    fn policy_names() {
+        assert_eq!(Policy::named("zero"), Some(Policy::Zero));
+        assert_eq!(Policy::named("Skip"), Some(Policy::Skip));
+        assert_eq!(Policy::named("ignore"), None);
+    }
+}
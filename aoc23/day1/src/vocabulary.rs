@@ -0,0 +1,199 @@
+//! Words standing for digits, such as `seven` or `sieben`, built in for a few languages or loaded
+//! from a file.
+
+use common::input::{load_file, InputError};
+use common::parse::{number, ParseError};
+use std::error::Error;
+use std::fmt;
+
+/// Number words of the built-in languages, from zero to nine.
+const LANGUAGES: [(&str, [&str; 10]); 4] = [
+    (
+        "english",
+        [
+            "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+        ],
+    ),
+    (
+        "german",
+        [
+            "null", "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+        ],
+    ),
+    (
+        "french",
+        [
+            "zéro", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf",
+        ],
+    ),
+    (
+        "romanian",
+        [
+            "zero", "unu", "doi", "trei", "patru", "cinci", "șase", "șapte", "opt", "nouă",
+        ],
+    ),
+];
+
+/// Words spelling out digits, each with the digit it stands for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vocabulary {
+    words: Vec<(String, u8)>,
+    ignore_case: bool,
+}
+
+impl Vocabulary {
+    /// Return the vocabulary of `words`, each with the digit it stands for.
+    pub fn new<S: Into<String>>(words: impl IntoIterator<Item = (S, u8)>) -> Self {
+        Vocabulary {
+            words: words.into_iter().map(|(w, d)| (w.into(), d)).collect(),
+            ignore_case: false,
+        }
+    }
+
+    /// Return the words of the puzzle, `one` to `nine` in English.
+    pub fn puzzle() -> Self {
+        let mut english = Self::language("english").unwrap();
+        english.words.retain(|&(_, digit)| digit != 0);
+        english
+    }
+
+    /// Return the words from zero to nine of the language `name`, among english, german, french
+    /// and romanian, or None if it is not one of them.
+    pub fn language(name: &str) -> Option<Self> {
+        let (_, words) = LANGUAGES
+            .iter()
+            .find(|(language, _)| language.eq_ignore_ascii_case(name))?;
+        Some(Self::new(words.iter().copied().zip(0..)))
+    }
+
+    /// Return the names of the built-in languages.
+    pub fn languages() -> impl Iterator<Item = &'static str> {
+        LANGUAGES.iter().map(|&(name, _)| name)
+    }
+
+    /// Parse a vocabulary with one digit per line followed by its words, separated by whitespace,
+    /// such as `7 seven sieben sept`. Blank lines and lines starting with `#` are skipped.
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let mut words = Vec::new();
+        for line in text.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut fields = line.split_whitespace();
+            let field = fields.next().unwrap();
+            let digit: u8 = number(field)?;
+            if digit > 9 {
+                return Err(ParseError::new(field, "expected a digit from 0 to 9"));
+            }
+            let len = words.len();
+            words.extend(fields.map(|word| (word.to_string(), digit)));
+            if words.len() == len {
+                return Err(ParseError::new(line, "expected words after the digit"));
+            }
+        }
+        Ok(Self::new(words))
+    }
+
+    /// Load the vocabulary file at `path`, in the format read by [`Vocabulary::parse`].
+    pub fn load(path: &str) -> Result<Self, VocabularyError> {
+        let text = load_file(path).map_err(VocabularyError::Input)?;
+        Self::parse(&text).map_err(|e| VocabularyError::Parse(e.locate(&text).with_file(path)))
+    }
+
+    /// Return the vocabulary with the words of `other` added to its own.
+    pub fn union(mut self, other: &Vocabulary) -> Self {
+        self.words.extend(other.words.iter().cloned());
+        self.ignore_case |= other.ignore_case;
+        self
+    }
+
+    /// Return the vocabulary matching its words whatever their case.
+    pub fn ignoring_case(mut self) -> Self {
+        self.ignore_case = true;
+        self
+    }
+
+    pub fn words(&self) -> &[(String, u8)] {
+        &self.words
+    }
+
+    pub fn ignores_case(&self) -> bool {
+        self.ignore_case
+    }
+}
+
+/// Vocabulary file that could not be loaded.
+#[derive(Debug)]
+pub enum VocabularyError {
+    Input(InputError),
+    Parse(ParseError),
+}
+
+impl fmt::Display for VocabularyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Input(e) => write!(f, "{}", e),
+            Self::Parse(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for VocabularyError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Input(e) => Some(e),
+            Self::Parse(e) => Some(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn languages() {
+        let german = Vocabulary::language("German").unwrap();
+        assert_eq!(german.words()[5], ("fünf".to_string(), 5));
+        assert_eq!(german.words().len(), 10);
+        assert_eq!(Vocabulary::language("klingon"), None);
+        assert_eq!(Vocabulary::languages().count(), 4);
+        let puzzle = Vocabulary::puzzle();
+        assert_eq!(puzzle.words().len(), 9);
+        assert_eq!(puzzle.words()[0], ("one".to_string(), 1));
+    }
+
+    #[test]
+    fn parse_file() {
+        let text = "# Two ways to spell seven\n\n7 seven  sieben\n0 zero\n";
+        assert_eq!(
+            Vocabulary::parse(text),
+            Ok(Vocabulary::new([("seven", 7), ("sieben", 7), ("zero", 0)]))
+        );
+        let err = Vocabulary::parse("1 one\n12 twelve\n").unwrap_err();
+        assert_eq!(err.message(), "expected a digit from 0 to 9");
+        let err = Vocabulary::parse("1\n").unwrap_err();
+        assert_eq!(err.message(), "expected words after the digit");
+        assert!(Vocabulary::parse("one 1\n").is_err());
+    }
+
+    #[test]
+    fn load_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("words.txt");
+        let path = path.to_str().unwrap();
+        fs::write(path, "3 drei\n4 vier\n").unwrap();
+        let vocabulary = Vocabulary::load(path).unwrap();
+        assert_eq!(vocabulary, Vocabulary::new([("drei", 3), ("vier", 4)]));
+        fs::write(path, "3 drei\nx vier\n").unwrap();
+        let err = Vocabulary::load(path).unwrap_err();
+        assert!(err.to_string().starts_with(&format!("{}:2:1: ", path)));
+        let missing = dir.path().join("missing.txt");
+        assert!(matches!(
+            Vocabulary::load(missing.to_str().unwrap()),
+            Err(VocabularyError::Input(InputError::Missing(_)))
+        ));
+    }
+}
@@ -0,0 +1,272 @@
+use common::geometry::Direction::{self, Down, Left, Right, Up};
+use common::grid::{Grid, Pos};
+use common::parse::ParseError;
+use common::{Solution, SolveError};
+use std::collections::HashSet;
+use std::fmt::Display;
+
+/// Pipes that connect back to a tile from each direction.
+const CONNECTIONS: [(Direction, &str); 4] =
+    [(Up, "|F7"), (Down, "|LJ"), (Left, "-FL"), (Right, "-J7")];
+
+/// Parse the maze of pipes, with a single start tile connected to two pipes.
+pub fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+    let maze = Grid::parse(input, |b| b"|-LJ7F.S".contains(&b).then_some(b))
+        .map_err(|e| e.in_text(input))?;
+    let Some(start) = maze.find(|&b| b == b'S') else {
+        return Err(ParseError::new(&input[input.len()..], "no start tile 'S'"));
+    };
+    if let Some((i, _)) = input.match_indices('S').nth(1) {
+        return Err(ParseError::new(&input[i..i + 1], "second start tile 'S'"));
+    }
+    let connected = CONNECTIONS
+        .into_iter()
+        .filter(|&(d, pipes)| connects(maze.step(start, d), pipes, &maze))
+        .count();
+    if connected < 2 {
+        let line = input.lines().nth(start.row).unwrap_or_default();
+        let tile = &line[start.col..start.col + 1];
+        return Err(ParseError::new(
+            tile,
+            "start tile 'S' is not connected to two pipes",
+        ));
+    }
+    Ok(maze)
+}
+
+/// Return whether `pos` is a tile of the maze among `pipes`.
+fn connects(pos: Option<Pos>, pipes: &str, maze: &Grid<u8>) -> bool {
+    pos.and_then(|pos| maze.get(pos))
+        .is_some_and(|&c| pipes.contains(c as char))
+}
+
+/// Return the number of steps to the farthest point of the loop.
+pub fn part1(maze: &Grid<u8>) -> usize {
+    get_loop(maze).len() / 2
+}
+
+/// Return the number of tiles enclosed by the loop.
+pub fn part2(maze: &Grid<u8>) -> u32 {
+    count_interior_tiles(&get_loop(maze), maze)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, SolveError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, SolveError> {
+        Ok(part2(input))
+    }
+}
+
+#[derive(PartialEq)]
+enum Side {
+    Interior,
+    Exterior,
+}
+
+struct Orientation {
+    side: Side,
+}
+
+impl Orientation {
+    fn flip(&mut self) {
+        match self.side {
+            Side::Exterior => self.side = Side::Interior,
+            Side::Interior => self.side = Side::Exterior,
+        }
+    }
+}
+
+pub fn count_interior_tiles(loop_items: &HashSet<Pos>, maze: &Grid<u8>) -> u32 {
+    let mut total = 0;
+
+    for (i, row) in maze.rows().enumerate() {
+        let mut corner: Option<u8> = None;
+        let mut orientation = Orientation {
+            side: Side::Exterior,
+        };
+        for (j, tile) in row.iter().enumerate() {
+            let loop_item = loop_items.contains(&Pos::new(i, j));
+            let interior_point = !loop_item && orientation.side == Side::Interior;
+            match tile {
+                b'.' => {
+                    if orientation.side == Side::Interior {
+                        total += 1;
+                    }
+                }
+                // does not change the orientation
+                b'-' => {
+                    if interior_point {
+                        total += 1;
+                    }
+                }
+                // flips the orientation
+                b'|' => {
+                    if interior_point {
+                        total += 1;
+                    } else if loop_item {
+                        orientation.flip();
+                    }
+                }
+                // flips the orientation if followed by a '7'
+                b'L' => {
+                    if interior_point {
+                        total += 1;
+                    } else if loop_item {
+                        corner = Some(b'L');
+                    }
+                }
+                // FIXME: handle this case in a generic way (now assume S=7)
+                // flips the orientation if there is an 'L' on the stack
+                b'7' | b'S' => {
+                    if interior_point {
+                        total += 1
+                    } else if loop_item && corner.is_some() {
+                        if corner.unwrap() == b'L' {
+                            orientation.flip();
+                            corner = None;
+                        } else if corner.unwrap() == b'F' {
+                            corner = None;
+                        }
+                    }
+                }
+                // flips the orientation if followed by a 'J'
+                b'F' => {
+                    if interior_point {
+                        total += 1;
+                    } else if loop_item {
+                        corner = Some(b'F');
+                    }
+                }
+                // flips the orientation if there is an 'F' on the stack
+                b'J' => {
+                    if interior_point {
+                        total += 1;
+                    } else if loop_item && corner.is_some() {
+                        if corner.unwrap() == b'F' {
+                            orientation.flip();
+                            corner = None;
+                        } else if corner.unwrap() == b'L' {
+                            corner = None;
+                        }
+                    }
+                }
+                _ => unreachable!("parse only accepts the tiles above"),
+            }
+        }
+    }
+
+    total
+}
+
+pub fn get_loop(maze: &Grid<u8>) -> HashSet<Pos> {
+    // Start position ('S')
+    let mut curr_pos = maze
+        .find(|&b| b == b'S')
+        .expect("parse checks that there is a start tile");
+
+    let mut seen_pos: HashSet<Pos> = HashSet::new();
+    seen_pos.insert(curr_pos);
+
+    // Special case for getting the next position from the start
+    curr_pos = get_first_move(curr_pos, maze);
+    let mut curr_char = get_char(curr_pos, maze);
+    seen_pos.insert(curr_pos);
+
+    while let Some(next_pos) = get_next_pos(&curr_char, &curr_pos, &seen_pos, maze) {
+        seen_pos.insert(next_pos);
+        curr_char = get_char(next_pos, maze);
+        curr_pos = next_pos;
+    }
+    seen_pos
+}
+
+// Return true if `next_pos` is within the maze boundaries and not ground, otherwise false.
+fn can_move(next_pos: Pos, maze: &Grid<u8>) -> bool {
+    maze.get(next_pos).is_some_and(|&c| c != b'.')
+}
+
+fn candidate_pos(curr_char: &u8, curr_pos: Pos) -> Vec<Pos> {
+    let directions = match curr_char {
+        b'-' => [Left, Right],
+        b'|' => [Up, Down],
+        b'J' => [Up, Left],
+        b'L' => [Up, Right],
+        b'F' => [Down, Right],
+        b'7' => [Down, Left],
+        _ => unreachable!("only pipes are followed from the start"),
+    };
+    directions
+        .into_iter()
+        .filter_map(|d| curr_pos.offset(d))
+        .collect()
+}
+
+fn get_first_move(curr_pos: Pos, maze: &Grid<u8>) -> Pos {
+    CONNECTIONS
+        .into_iter()
+        .find(|&(d, pipes)| connects(maze.step(curr_pos, d), pipes, maze))
+        .and_then(|(d, _)| maze.step(curr_pos, d))
+        .expect("parse checks that the start is connected")
+}
+
+fn get_char(pos: Pos, maze: &Grid<u8>) -> u8 {
+    maze[pos]
+}
+
+fn get_next_pos(
+    curr_char: &u8,
+    curr_pos: &Pos,
+    seen_pos: &HashSet<Pos>,
+    maze: &Grid<u8>,
+) -> Option<Pos> {
+    let candidates = candidate_pos(curr_char, *curr_pos);
+    candidates
+        .into_iter()
+        .find(|pos| can_move(*pos, maze) && !seen_pos.contains(pos))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn start_tile() {
+        let input = ".....\n.F-7.\n.|.|.\n.L-J.\n.....\n";
+        let err = parse(input).unwrap_err().locate(input);
+        assert_eq!(err.message(), "no start tile 'S'");
+        assert_eq!(err.location().map(|loc| loc.line), Some(6));
+
+        let input = ".....\n.S-7.\n.|.|.\n.L-J.\n.....\n";
+        assert_eq!(part1(&parse(input).unwrap()), 4);
+        let input = ".....\n.S-7.\n...|.\n.L-J.\n.....\n";
+        let err = parse(input).unwrap_err().locate(input);
+        assert_eq!(
+            err.message(),
+            "start tile 'S' is not connected to two pipes"
+        );
+        assert_eq!(
+            err.location().map(|loc| (loc.line, loc.column)),
+            Some((2, 2))
+        );
+
+        let input = "S7\nLS\n";
+        let err = parse(input).unwrap_err().locate(input);
+        assert_eq!(err.message(), "second start tile 'S'");
+        assert_eq!(
+            err.location().map(|loc| (loc.line, loc.column)),
+            Some((2, 2))
+        );
+    }
+}
@@ -0,0 +1,129 @@
+use common::grid::{Grid, Pos};
+use common::parse::ParseError;
+use common::{Solution, SolveError};
+use std::collections::HashSet;
+use std::fmt::Display;
+
+/// Parse the image of the universe.
+pub fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse(input, |b| b".#".contains(&b).then_some(b)).map_err(|e| e.in_text(input))
+}
+
+/// Return the sum of the galaxy distances when empty rows and columns are twice as big.
+pub fn part1(universe: &Grid<u8>) -> i64 {
+    get_total_galaxy_distances(universe, 2)
+}
+
+/// Return the sum of the galaxy distances when empty rows and columns are a million times as
+/// big.
+pub fn part2(universe: &Grid<u8>) -> i64 {
+    get_total_galaxy_distances(universe, 1_000_000)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, SolveError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, SolveError> {
+        Ok(part2(input))
+    }
+}
+
+struct Expansions {
+    rows: HashSet<usize>,
+    cols: HashSet<usize>,
+}
+
+#[derive(Debug, PartialEq, Hash, Eq)]
+struct Galaxy {
+    position: Pos,
+}
+
+impl Galaxy {
+    fn dist(&self, other: &Galaxy, expansions: &Expansions, expansion_factor: i64) -> i64 {
+        let row_range = if self.position.row < other.position.row {
+            self.position.row..other.position.row
+        } else {
+            other.position.row..self.position.row
+        };
+        let num_row_expansions: HashSet<usize> = HashSet::from_iter(row_range)
+            .intersection(&expansions.rows)
+            .cloned()
+            .collect();
+
+        let col_range = if self.position.col < other.position.col {
+            self.position.col..other.position.col
+        } else {
+            other.position.col..self.position.col
+        };
+        let num_col_expansions: HashSet<usize> = HashSet::from_iter(col_range)
+            .intersection(&expansions.cols)
+            .cloned()
+            .collect();
+        self.position.manhattan(other.position) as i64
+            + num_row_expansions.len() as i64 * expansion_factor
+            - num_row_expansions.len() as i64
+            + num_col_expansions.len() as i64 * expansion_factor
+            - num_col_expansions.len() as i64
+    }
+}
+
+pub fn get_total_galaxy_distances(universe: &Grid<u8>, expansion_factor: i64) -> i64 {
+    let galaxies = get_galaxies(universe);
+    let expansions = get_expansions(&galaxies, universe);
+    get_pairwise_distances(&galaxies, &expansions, expansion_factor)
+}
+
+fn get_pairwise_distances(
+    galaxies: &HashSet<Galaxy>,
+    expansions: &Expansions,
+    expansion_factor: i64,
+) -> i64 {
+    let mut seen: HashSet<(&Galaxy, &Galaxy)> = HashSet::new();
+    let mut total = 0;
+    for g1 in galaxies {
+        for g2 in galaxies {
+            if g1 != g2 && !seen.contains(&(g2, g1)) {
+                total += g1.dist(g2, expansions, expansion_factor);
+            }
+            seen.insert((g1, g2));
+        }
+    }
+    total
+}
+
+fn get_expansions(galaxies: &HashSet<Galaxy>, universe: &Grid<u8>) -> Expansions {
+    let (nrow, ncol) = (universe.nrow(), universe.ncol());
+    let occupied_rows: HashSet<usize> = galaxies.iter().map(|g| g.position.row).collect();
+    let occupied_cols: HashSet<usize> = galaxies.iter().map(|g| g.position.col).collect();
+    let empty_rows = HashSet::from_iter(1..nrow)
+        .difference(&occupied_rows)
+        .cloned()
+        .collect();
+    let empty_cols = HashSet::from_iter(1..ncol)
+        .difference(&occupied_cols)
+        .cloned()
+        .collect();
+    Expansions {
+        rows: empty_rows,
+        cols: empty_cols,
+    }
+}
+
+fn get_galaxies(universe: &Grid<u8>) -> HashSet<Galaxy> {
+    universe
+        .iter()
+        .filter(|(_, item)| **item == b'#')
+        .map(|(position, _)| Galaxy { position })
+        .collect()
+}
@@ -0,0 +1,173 @@
+use common::parse::{number, split_once, ParseError};
+use common::{Solution, SolveError};
+use std::collections::HashMap;
+use std::fmt::Display;
+
+/// A row of springs, each operational (`.`), damaged (`#`) or unknown (`?`), and the sizes of its
+/// contiguous groups of damaged springs in order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub springs: Vec<u8>,
+    pub groups: Vec<usize>,
+}
+
+impl Record {
+    /// Return the record unfolded five times: the springs joined by unknown springs, and the
+    /// groups repeated.
+    pub fn unfold(&self) -> Record {
+        let mut springs = self.springs.clone();
+        for _ in 1..5 {
+            springs.push(b'?');
+            springs.extend(&self.springs);
+        }
+        Record {
+            springs,
+            groups: self.groups.repeat(5),
+        }
+    }
+
+    /// Return the number of ways to fill in the unknown springs that agree with the groups.
+    pub fn arrangements(&self) -> u64 {
+        count(&self.springs, &self.groups, &mut HashMap::new())
+    }
+}
+
+/// Return the number of arrangements of `springs` with the damaged `groups`. Both are suffixes of
+/// the record, so their lengths identify them in `memo`.
+fn count(springs: &[u8], groups: &[usize], memo: &mut HashMap<(usize, usize), u64>) -> u64 {
+    let Some((&group, rest)) = groups.split_first() else {
+        return u64::from(!springs.contains(&b'#'));
+    };
+    if springs.len() < group {
+        return 0;
+    }
+    if let Some(&n) = memo.get(&(springs.len(), groups.len())) {
+        return n;
+    }
+
+    let mut total = 0;
+    // The first spring is operational.
+    if springs[0] != b'#' {
+        total += count(&springs[1..], groups, memo);
+    }
+    // The first group starts here, and is followed by an operational spring or the end.
+    if springs[0] != b'.' && !springs[..group].contains(&b'.') && springs.get(group) != Some(&b'#')
+    {
+        let next = (group + 1).min(springs.len());
+        total += count(&springs[next..], rest, memo);
+    }
+
+    memo.insert((springs.len(), groups.len()), total);
+    total
+}
+
+/// Return the sum of the arrangements of every record.
+pub fn part1(records: &[Record]) -> u64 {
+    records.iter().map(Record::arrangements).sum()
+}
+
+/// Return the sum of the arrangements of every record once unfolded.
+pub fn part2(records: &[Record]) -> u64 {
+    records.iter().map(|r| r.unfold().arrangements()).sum()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Input = Vec<Record>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, SolveError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, SolveError> {
+        Ok(part2(input))
+    }
+}
+
+/// Parse the condition records, one per line, such as `???.### 1,1,3`.
+pub fn parse(input: &str) -> Result<Vec<Record>, ParseError> {
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let (springs, groups) = split_once(line, " ")?;
+            if let Some(i) = springs.find(|c| !".#?".contains(c)) {
+                let bad = &springs[i..i + springs[i..].chars().next().map_or(1, char::len_utf8)];
+                return Err(ParseError::new(bad, "expected a spring '.', '#' or '?'"));
+            }
+            Ok(Record {
+                springs: springs.as_bytes().to_vec(),
+                groups: groups.split(',').map(number).collect::<Result<_, _>>()?,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RECORDS: &str = "???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1";
+
+    #[test]
+    fn parse_record() {
+        assert_eq!(
+            parse("???.### 1,1,3"),
+            Ok(vec![Record {
+                springs: b"???.###".to_vec(),
+                groups: vec![1, 1, 3],
+            }])
+        );
+        let input = "???.### 1,1,3\n??x 1\n";
+        let loc = parse(input).unwrap_err().locate(input).location().cloned();
+        assert_eq!(loc.map(|loc| (loc.line, loc.column)), Some((2, 3)));
+    }
+
+    #[test]
+    fn unfold_record() {
+        let record = parse(".# 1").unwrap().remove(0).unfold();
+        assert_eq!(record.springs, b".#?.#?.#?.#?.#".to_vec());
+        assert_eq!(record.groups, vec![1; 5]);
+    }
+
+    #[test]
+    fn arrangements_folded() {
+        let counts: Vec<u64> = parse(RECORDS)
+            .unwrap()
+            .iter()
+            .map(Record::arrangements)
+            .collect();
+        assert_eq!(counts, vec![1, 4, 1, 1, 4, 10]);
+        assert_eq!(part1(&parse(RECORDS).unwrap()), 21);
+    }
+
+    #[test]
+    fn arrangements_unfolded() {
+        let counts: Vec<u64> = parse(RECORDS)
+            .unwrap()
+            .iter()
+            .map(|r| r.unfold().arrangements())
+            .collect();
+        assert_eq!(counts, vec![1, 16384, 1, 16, 2500, 506250]);
+        assert_eq!(part2(&parse(RECORDS).unwrap()), 525152);
+    }
+
+    #[test]
+    fn arrangements_edge_cases() {
+        assert_eq!(parse("### 3").unwrap()[0].arrangements(), 1);
+        assert_eq!(parse("#.# 3").unwrap()[0].arrangements(), 0);
+        assert_eq!(parse("??? 1").unwrap()[0].arrangements(), 3);
+        assert_eq!(parse("?#? 1,1").unwrap()[0].arrangements(), 0);
+    }
+}
@@ -0,0 +1,24 @@
+use common::input::load_file;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let input = match load_file("./data/input.txt") {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let parsed = match day12::parse(&input) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("{}", e.locate(&input).with_file("./data/input.txt"));
+            return ExitCode::FAILURE;
+        }
+    };
+
+    println!("Day 12, Part 1: {}", day12::part1(&parsed));
+    println!("Day 12, Part 2: {}", day12::part2(&parsed));
+
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,225 @@
+use common::grid::Grid;
+use common::parse::ParseError;
+use common::{Solution, SolveError};
+use std::fmt::Display;
+
+/// A pattern of ash (`.`) and rocks (`#`).
+pub type Pattern = Grid<u8>;
+
+/// Parse the patterns of ash and rocks, separated by one or more blank lines.
+pub fn parse(input: &str) -> Result<Vec<Pattern>, ParseError> {
+    input
+        .split("\n\n")
+        .map(|pat| pat.trim_matches('\n'))
+        .filter(|pat| !pat.is_empty())
+        .map(|pat| {
+            Grid::parse(pat, |b| b".#".contains(&b).then_some(b)).map_err(|e| e.in_text(pat))
+        })
+        .collect()
+}
+
+/// Return true if row i and row j are equal.
+fn equal_rows(pat: &Pattern, i: usize, j: usize) -> bool {
+    pat.row(i) == pat.row(j)
+}
+
+fn candidate_horizontal_reflections(pat: &Pattern) -> Vec<usize> {
+    (0..pat.nrow() - 1)
+        .filter(|i| equal_rows(pat, *i, *i + 1))
+        .map(|i| i + 1)
+        .collect()
+}
+
+fn perfect_horizontal_reflection(pat: &Pattern) -> Option<usize> {
+    let candidates = candidate_horizontal_reflections(pat);
+    candidates.into_iter().find(|i| {
+        (0..*i)
+            .rev()
+            .zip(*i..pat.nrow())
+            .all(|(i, j)| equal_rows(pat, i, j))
+    })
+}
+
+/// The vertical reflections of a pattern are the horizontal ones of its transpose.
+fn perfect_vertical_reflection(pat: &Pattern) -> Option<usize> {
+    perfect_horizontal_reflection(&pat.transpose())
+}
+
+/// Same as `equal_rows()` but allow for one smudge. Return a tuple with
+/// first element a bool indicating if rows are equal, and the second
+/// element is the length of mismatches. For `true` only `(true, 0)` and
+/// `(true, 1)` can happen.
+fn equal_rows_with_smudge(pat: &Pattern, i: usize, j: usize) -> (bool, usize) {
+    let matches = pat
+        .row(i)
+        .iter()
+        .zip(pat.row(j))
+        .filter(|(left, right)| left == right)
+        .count();
+    (matches >= pat.ncol() - 1, pat.ncol() - matches)
+}
+
+fn candidate_horizontal_reflections_with_smudge(pat: &Pattern) -> Vec<usize> {
+    (0..pat.nrow() - 1)
+        .filter(|i| equal_rows_with_smudge(pat, *i, *i + 1).0)
+        .map(|i| i + 1)
+        .collect()
+}
+
+fn perfect_horizontal_reflection_with_smudge(pat: &Pattern) -> Option<usize> {
+    let candidates = candidate_horizontal_reflections_with_smudge(pat);
+    let diffs = candidates.iter().map(|i| {
+        (0..*i)
+            .rev()
+            .zip(*i..pat.nrow())
+            .map(|(i, j)| equal_rows_with_smudge(pat, i, j).1)
+            .sum()
+    });
+    let ix: Option<usize> = diffs.into_iter().position(|d: usize| d == 1);
+    ix.map(|i| candidates[i])
+}
+
+fn perfect_vertical_reflection_with_smudge(pat: &Pattern) -> Option<usize> {
+    perfect_horizontal_reflection_with_smudge(&pat.transpose())
+}
+
+/// Return the summary of the reflection lines of the patterns.
+pub fn part1(patterns: &[Pattern]) -> u32 {
+    let mut total = 0;
+    for pat in patterns {
+        if let Some(i) = perfect_horizontal_reflection(pat) {
+            total += 100 * i as u32;
+            continue;
+        }
+        if let Some(j) = perfect_vertical_reflection(pat) {
+            total += j as u32;
+        }
+    }
+    total
+}
+
+/// Return the summary of the reflection lines of the patterns once each smudge is fixed.
+pub fn part2(patterns: &[Pattern]) -> u32 {
+    let mut total = 0;
+    for pat in patterns {
+        if let Some(i) = perfect_horizontal_reflection_with_smudge(pat) {
+            total += 100 * i as u32;
+            continue;
+        }
+        if let Some(j) = perfect_vertical_reflection_with_smudge(pat) {
+            total += j as u32;
+        }
+    }
+    total
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Input = Vec<Pattern>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, SolveError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, SolveError> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAT_STR_1: &str = "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.";
+
+    const MAT_STR_2: &str = "#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+
+    #[test]
+    fn pattern_cols_equal() {
+        let m = Grid::bytes(MAT_STR_1).unwrap().transpose();
+        assert!(equal_rows(&m, 4, 5));
+        assert!(equal_rows(&m, 3, 6));
+        assert!(equal_rows(&m, 2, 7));
+        assert!(equal_rows(&m, 1, 8));
+        assert!(!equal_rows(&m, 0, 1));
+    }
+
+    #[test]
+    fn pattern_rows_equal() {
+        let m = Grid::bytes(MAT_STR_2).unwrap();
+        assert!(equal_rows(&m, 3, 4));
+        assert!(equal_rows(&m, 2, 5));
+        assert!(equal_rows(&m, 1, 6));
+        assert!(!equal_rows(&m, 0, 1));
+    }
+
+    #[test]
+    fn pattern_horizontal_reflections() {
+        let m1 = Grid::bytes(MAT_STR_1).unwrap();
+        let m2 = Grid::bytes(MAT_STR_2).unwrap();
+        assert_eq!(candidate_horizontal_reflections(&m1), vec![3]);
+        assert_eq!(candidate_horizontal_reflections(&m2), vec![4]);
+    }
+
+    #[test]
+    fn pattern_vertical_reflections() {
+        let m1 = Grid::bytes(MAT_STR_1).unwrap().transpose();
+        let m2 = Grid::bytes(MAT_STR_2).unwrap().transpose();
+        assert_eq!(candidate_horizontal_reflections(&m1), vec![5]);
+        assert_eq!(candidate_horizontal_reflections(&m2), vec![3, 7]);
+    }
+
+    #[test]
+    fn pattern_perfect_horizontal_reflections() {
+        let m1 = Grid::bytes(MAT_STR_1).unwrap();
+        let m2 = Grid::bytes(MAT_STR_2).unwrap();
+        assert_eq!(perfect_horizontal_reflection(&m1), None);
+        assert_eq!(perfect_horizontal_reflection(&m2), Some(4));
+    }
+
+    #[test]
+    fn pattern_perfect_vertical_reflections() {
+        let m1 = Grid::bytes(MAT_STR_1).unwrap();
+        let m2 = Grid::bytes(MAT_STR_2).unwrap();
+        assert_eq!(perfect_vertical_reflection(&m1), Some(5));
+        assert_eq!(perfect_vertical_reflection(&m2), None);
+    }
+
+    #[test]
+    fn pattern_smudged_reflections() {
+        let m1 = Grid::bytes(MAT_STR_1).unwrap();
+        let m2 = Grid::bytes(MAT_STR_2).unwrap();
+        assert_eq!(perfect_horizontal_reflection_with_smudge(&m1), Some(3));
+        assert_eq!(perfect_horizontal_reflection_with_smudge(&m2), Some(1));
+    }
+
+    #[test]
+    fn blank_line_runs() {
+        for input in ["#.\n.#\n\n\n.#\n", "#.\n.#\n\n\n\n.#\n\n\n"] {
+            let patterns = parse(input).unwrap();
+            assert_eq!(patterns.len(), 2, "{:?}", input);
+            assert_eq!(patterns[1].nrow(), 1, "{:?}", input);
+        }
+        let input = "#.\n\n\n.#.\n..\n";
+        let err = parse(input).unwrap_err().locate(input);
+        assert_eq!(err.location().map(|loc| loc.line), Some(5));
+    }
+}
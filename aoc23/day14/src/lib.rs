@@ -0,0 +1,208 @@
+use common::grid::{Grid, Pos};
+use common::parse::ParseError;
+use common::{Solution, SolveError};
+use std::collections::HashMap;
+use std::fmt::Display;
+
+const ROUND: u8 = b'O';
+const EMPTY: u8 = b'.';
+
+/// Number of spin cycles of part 2.
+const CYCLES: usize = 1_000_000_000;
+
+/// Direction in which the platform is tilted, and the round rocks roll.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tilt {
+    North,
+    West,
+    South,
+    East,
+}
+
+/// Parse the platform of round rocks (`O`), cube rocks (`#`) and empty spaces (`.`).
+pub fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse(input, |b| b"O#.".contains(&b).then_some(b)).map_err(|e| e.in_text(input))
+}
+
+/// Roll every round rock of `platform` as far as it goes towards `tilt`.
+pub fn tilt(platform: &mut Grid<u8>, tilt: Tilt) {
+    let (nrow, ncol) = (platform.nrow(), platform.ncol());
+    // Each line runs against the tilt, from the edge the rocks roll to.
+    let (lines, len) = match tilt {
+        Tilt::North | Tilt::South => (ncol, nrow),
+        Tilt::West | Tilt::East => (nrow, ncol),
+    };
+    let at = |line: usize, k: usize| match tilt {
+        Tilt::North => Pos::new(k, line),
+        Tilt::South => Pos::new(nrow - 1 - k, line),
+        Tilt::West => Pos::new(line, k),
+        Tilt::East => Pos::new(line, ncol - 1 - k),
+    };
+
+    for line in 0..lines {
+        let mut free = 0;
+        for k in 0..len {
+            let pos = at(line, k);
+            match platform[pos] {
+                ROUND => {
+                    platform[pos] = EMPTY;
+                    platform[at(line, free)] = ROUND;
+                    free += 1;
+                }
+                EMPTY => {}
+                _ => free = k + 1,
+            }
+        }
+    }
+}
+
+/// Tilt `platform` north, west, south then east.
+pub fn spin_cycle(platform: &mut Grid<u8>) {
+    for t in [Tilt::North, Tilt::West, Tilt::South, Tilt::East] {
+        tilt(platform, t);
+    }
+}
+
+/// Return the platform after `n` spin cycles. Once a platform repeats, the cycles loop, so only
+/// the first loop and the remainder are run.
+pub fn spin_cycles(platform: &Grid<u8>, n: usize) -> Grid<u8> {
+    let mut seen: HashMap<Grid<u8>, usize> = HashMap::new();
+    let mut platform = platform.clone();
+    let mut done = 0;
+    while done < n {
+        if let Some(start) = seen.insert(platform.clone(), done) {
+            let period = done - start;
+            for _ in 0..(n - done) % period {
+                spin_cycle(&mut platform);
+            }
+            return platform;
+        }
+        spin_cycle(&mut platform);
+        done += 1;
+    }
+    platform
+}
+
+/// Return the total load on the north support beams: each round rock weighs the number of rows
+/// from it to the south edge, its own included.
+pub fn north_load(platform: &Grid<u8>) -> usize {
+    platform
+        .rows()
+        .enumerate()
+        .map(|(i, row)| (platform.nrow() - i) * row.iter().filter(|&&b| b == ROUND).count())
+        .sum()
+}
+
+/// Return the load on the north beams once the platform is tilted north.
+pub fn part1(platform: &Grid<u8>) -> usize {
+    let mut platform = platform.clone();
+    tilt(&mut platform, Tilt::North);
+    north_load(&platform)
+}
+
+/// Return the load on the north beams after a billion spin cycles.
+pub fn part2(platform: &Grid<u8>) -> usize {
+    north_load(&spin_cycles(platform, CYCLES))
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Input = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, SolveError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, SolveError> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PLATFORM: &str = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....
+";
+
+    #[test]
+    fn tilt_north() {
+        let mut platform = parse(PLATFORM).unwrap();
+        tilt(&mut platform, Tilt::North);
+        assert_eq!(
+            platform.to_string(),
+            "OOOO.#.O..
+OO..#....#
+OO..O##..O
+O..#.OO...
+........#.
+..#....#.#
+..O..#.O.O
+..O.......
+#....###..
+#....#....
+"
+        );
+        assert_eq!(north_load(&platform), 136);
+    }
+
+    #[test]
+    fn tilt_each_way() {
+        let row = |s: &str, t: Tilt| {
+            let mut platform = parse(s).unwrap();
+            tilt(&mut platform, t);
+            platform.to_string()
+        };
+        assert_eq!(row(".O#.O.\n", Tilt::West), "O.#O..\n");
+        assert_eq!(row(".O#.O.\n", Tilt::East), ".O#..O\n");
+        assert_eq!(row("O\n.\n#\nO\n.\n", Tilt::South), ".\nO\n#\n.\nO\n");
+    }
+
+    #[test]
+    fn spin_cycles_sample() {
+        let mut platform = parse(PLATFORM).unwrap();
+        spin_cycle(&mut platform);
+        assert_eq!(
+            platform.to_string(),
+            ".....#....
+....#...O#
+...OO##...
+.OO#......
+.....OOO#.
+.O#...O#.#
+....O#....
+......OOOO
+#...O###..
+#..OO#....
+"
+        );
+        for n in 0..20 {
+            let mut expected = parse(PLATFORM).unwrap();
+            for _ in 0..n {
+                spin_cycle(&mut expected);
+            }
+            assert_eq!(spin_cycles(&parse(PLATFORM).unwrap(), n), expected);
+        }
+    }
+
+    #[test]
+    fn loads() {
+        assert_eq!(part1(&parse(PLATFORM).unwrap()), 136);
+        assert_eq!(part2(&parse(PLATFORM).unwrap()), 64);
+    }
+}
@@ -0,0 +1,227 @@
+use common::parse::{number, ParseError};
+use common::{Solution, SolveError};
+use std::fmt::Display;
+
+/// Parse the initialization sequence, which is the first line of the input, checking that each
+/// step is a label followed by `-` or by `=` and a focal length.
+pub fn parse(input: &str) -> Result<String, ParseError> {
+    let init_seqs = input.lines().next().unwrap_or_default();
+    for step in init_seqs.trim().split(',') {
+        let label_len = step.bytes().take_while(u8::is_ascii_alphabetic).count();
+        let op = &step[label_len..];
+        if label_len == 0 {
+            return Err(ParseError::new(step, "expected a label"));
+        }
+        match op.strip_prefix('=') {
+            Some(fl) => {
+                number::<u32>(fl)?;
+            }
+            None if op == "-" => {}
+            None => {
+                return Err(ParseError::new(
+                    op,
+                    "expected '-' or '=' and a focal length",
+                ))
+            }
+        }
+    }
+    Ok(init_seqs.to_string())
+}
+
+/// Return the sum of the HASH of each step of the initialization sequence.
+pub fn part1(init_seqs: &str) -> u64 {
+    init_seqs
+        .trim()
+        .split(',')
+        .map(|s| hash_seq(s) as u64)
+        .sum()
+}
+
+/// Return the focusing power of the lens configuration.
+pub fn part2(init_seqs: &str) -> u64 {
+    let mut boxes: Vec<Vec<(String, u32)>> = vec![Vec::new(); 256];
+    for seq in init_seqs.trim().split(',') {
+        let (i, op) = (hash_label(seq), get_op(seq));
+        let label = get_label(seq);
+        match op {
+            b'=' => {
+                let fl = get_focal_length(seq).unwrap();
+                if let Some(ix) = boxes[i].iter().position(|x| *x.0 == label) {
+                    boxes[i][ix] = (label, fl);
+                } else {
+                    boxes[i].push((label, fl));
+                }
+            }
+            b'-' => {
+                if let Some(ix) = boxes[i].iter().position(|x| x.0 == label) {
+                    boxes[i].remove(ix);
+                }
+            }
+            _ => panic!("unknown symbol"),
+        }
+    }
+    boxes
+        .iter()
+        .enumerate()
+        .map(|(bi, bx)| {
+            bx.iter()
+                .enumerate()
+                .map(|(si, (_, fl))| (bi + 1) as u64 * (si + 1) as u64 * (*fl) as u64)
+                .sum::<u64>()
+        })
+        .sum()
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, SolveError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, SolveError> {
+        Ok(part2(input))
+    }
+}
+
+fn get_focal_length(s: &str) -> Option<u32> {
+    if s.contains('=') {
+        Some(s.split('=').next_back().unwrap().parse::<u32>().unwrap())
+    } else {
+        None
+    }
+}
+
+fn get_op(s: &str) -> u8 {
+    if s.contains('=') {
+        b'='
+    } else {
+        b'-'
+    }
+}
+
+fn hash_label(s: &str) -> usize {
+    let mut curr_val = 0;
+    for b in s.as_bytes() {
+        if b.is_ascii_alphabetic() {
+            curr_val += *b as u32;
+            curr_val *= 17;
+            curr_val %= 256
+        } else {
+            break;
+        }
+    }
+    curr_val as usize
+}
+
+fn get_label(s: &str) -> String {
+    String::from_utf8(
+        s.as_bytes()
+            .iter()
+            .take_while(|b| b.is_ascii_alphabetic())
+            .copied()
+            .collect::<Vec<u8>>(),
+    )
+    .unwrap()
+}
+
+fn hash_seq(s: &str) -> u32 {
+    let mut curr_val = 0;
+    for b in s.as_bytes() {
+        curr_val += *b as u32;
+        curr_val *= 17;
+        curr_val %= 256
+    }
+    curr_val
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hash_algo_works() {
+        assert_eq!(hash_seq("rn=1"), 30);
+        assert_eq!(hash_seq("cm-"), 253);
+        assert_eq!(hash_seq("qp=3"), 97);
+        assert_eq!(hash_seq("cm=2"), 47);
+        assert_eq!(hash_seq("qp-"), 14);
+        assert_eq!(hash_seq("pc=4"), 180);
+        assert_eq!(hash_seq("ot=9"), 9);
+        assert_eq!(hash_seq("ab=5"), 197);
+        assert_eq!(hash_seq("pc-"), 48);
+        assert_eq!(hash_seq("pc=6"), 214);
+        assert_eq!(hash_seq("ot=7"), 231);
+    }
+
+    #[test]
+    fn hash_label_works() {
+        assert_eq!(hash_label("rn=1"), 0);
+        assert_eq!(hash_label("cm-"), 0);
+        assert_eq!(hash_label("qp=3"), 1);
+        assert_eq!(hash_label("pc=4"), 3);
+        assert_eq!(hash_label("ot=9"), 3);
+    }
+
+    #[test]
+    fn focal_length_works() {
+        assert_eq!(get_focal_length("rn=1"), Some(1));
+        assert_eq!(get_focal_length("qp=3"), Some(3));
+        assert_eq!(get_focal_length("pc=4"), Some(4));
+        assert_eq!(get_focal_length("ot=9"), Some(9));
+        assert_eq!(get_focal_length("cm-"), None);
+    }
+
+    #[test]
+    fn get_op_works() {
+        assert_eq!(get_op("rn=1"), b'=');
+        assert_eq!(get_op("cm-"), b'-');
+        assert_eq!(get_op("qp=3"), b'=');
+        assert_eq!(get_op("cm=2"), b'=');
+        assert_eq!(get_op("qp-"), b'-');
+        assert_eq!(get_op("pc=4"), b'=');
+        assert_eq!(get_op("ot=9"), b'=');
+        assert_eq!(get_op("ab=5"), b'=');
+        assert_eq!(get_op("pc-"), b'-');
+        assert_eq!(get_op("pc=6"), b'=');
+        assert_eq!(get_op("ot=7"), b'=');
+    }
+
+    #[test]
+    fn get_label_works() {
+        assert_eq!(get_label("rn=1"), "rn");
+        assert_eq!(get_label("cm-"), "cm");
+        assert_eq!(get_label("qp=3"), "qp");
+        assert_eq!(get_label("cm=2"), "cm");
+        assert_eq!(get_label("qp-"), "qp");
+        assert_eq!(get_label("pc=4"), "pc");
+        assert_eq!(get_label("ot=9"), "ot");
+        assert_eq!(get_label("ab=5"), "ab");
+        assert_eq!(get_label("pc-"), "pc");
+        assert_eq!(get_label("pc=6"), "pc");
+        assert_eq!(get_label("ot=7"), "ot");
+    }
+
+    #[test]
+    fn malformed_steps() {
+        let err = parse("rn=1,cm+").unwrap_err();
+        assert_eq!(err.message(), "expected '-' or '=' and a focal length");
+        assert_eq!(parse("rn=1,=2").unwrap_err().message(), "expected a label");
+        assert!(parse("rn=x").is_err());
+    }
+
+    #[test]
+    fn hash_seq_works() {
+        assert_eq!(
+            part1("rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7"),
+            1320
+        );
+    }
+}
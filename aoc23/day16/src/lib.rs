@@ -0,0 +1,254 @@
+use common::geometry::Direction;
+use common::grid::{Grid, Pos};
+use common::parse::ParseError;
+use common::{Solution, SolveError};
+use rand::distributions::Alphanumeric;
+use rand::{thread_rng, Rng};
+use std::collections::{HashSet, VecDeque};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+pub type Map = Grid<Tile>;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Tile {
+    Empty,
+    LeftMirror,
+    RightMirror,
+    HorizontalSplitter,
+    VerticalSplitter,
+}
+
+impl Tile {
+    fn new(symbol: u8) -> Option<Self> {
+        match symbol {
+            b'\\' => Some(Self::LeftMirror),
+            b'/' => Some(Self::RightMirror),
+            b'-' => Some(Self::HorizontalSplitter),
+            b'|' => Some(Self::VerticalSplitter),
+            b'.' => Some(Self::Empty),
+            _ => None,
+        }
+    }
+}
+
+impl From<Tile> for char {
+    fn from(tile: Tile) -> char {
+        match tile {
+            Tile::LeftMirror => '\\',
+            Tile::RightMirror => '/',
+            Tile::HorizontalSplitter => '-',
+            Tile::VerticalSplitter => '|',
+            Tile::Empty => '.',
+        }
+    }
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", char::from(*self))
+    }
+}
+
+struct Beam {
+    id: String,
+    pos: Pos,
+    tile: Tile,
+    direction: Direction,
+    completed: bool,
+    seen: HashSet<Pos>,
+}
+
+impl Hash for Beam {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl PartialEq for Beam {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Beam {}
+
+impl Beam {
+    fn new(pos: Pos, tile: Tile, direction: Direction) -> Self {
+        Beam {
+            id: rand_id(),
+            pos,
+            tile,
+            direction,
+            completed: false,
+            seen: HashSet::new(),
+        }
+    }
+
+    fn has_split(&self, splits: &mut HashSet<Pos>) -> bool {
+        ((self.tile == Tile::VerticalSplitter
+            && (self.direction == Direction::Right || self.direction == Direction::Left))
+            || (self.tile == Tile::HorizontalSplitter
+                && (self.direction == Direction::Up || self.direction == Direction::Down)))
+            && splits.contains(&self.pos)
+    }
+
+    fn update(&mut self, beams: &mut VecDeque<Beam>, splits: &mut HashSet<Pos>, map: &Map) {
+        self.seen.insert(self.pos);
+        let mut new_beam: Option<Beam> = None;
+        // get the new direction given the current direction and current tile
+        let new_dir = match self.direction {
+            Direction::Up => match self.tile {
+                Tile::Empty => Direction::Up,
+                Tile::LeftMirror => Direction::Left,
+                Tile::RightMirror => Direction::Right,
+                Tile::VerticalSplitter => Direction::Up,
+                Tile::HorizontalSplitter => {
+                    new_beam = Some(Beam::new(self.pos, self.tile, Direction::Left));
+                    Direction::Right
+                }
+            },
+            Direction::Down => match self.tile {
+                Tile::Empty => Direction::Down,
+                Tile::LeftMirror => Direction::Right,
+                Tile::RightMirror => Direction::Left,
+                Tile::VerticalSplitter => Direction::Down,
+                Tile::HorizontalSplitter => {
+                    new_beam = Some(Beam::new(self.pos, self.tile, Direction::Left));
+                    Direction::Right
+                }
+            },
+            Direction::Left => match self.tile {
+                Tile::Empty => Direction::Left,
+                Tile::LeftMirror => Direction::Up,
+                Tile::RightMirror => Direction::Down,
+                Tile::VerticalSplitter => {
+                    new_beam = Some(Beam::new(self.pos, self.tile, Direction::Down));
+                    Direction::Up
+                }
+                Tile::HorizontalSplitter => Direction::Left,
+            },
+            Direction::Right => match self.tile {
+                Tile::Empty => Direction::Right,
+                Tile::LeftMirror => Direction::Down,
+                Tile::RightMirror => Direction::Up,
+                Tile::VerticalSplitter => {
+                    new_beam = Some(Beam::new(self.pos, self.tile, Direction::Down));
+                    Direction::Up
+                }
+                Tile::HorizontalSplitter => Direction::Right,
+            },
+        };
+        if !self.has_split(splits) {
+            if let Some(b) = new_beam {
+                splits.insert(self.pos);
+                beams.push_back(b);
+            }
+        }
+
+        if let Some(new_pos) = map.step(self.pos, new_dir) {
+            self.pos = new_pos;
+            self.direction = new_dir;
+            self.tile = map[new_pos];
+        } else {
+            self.completed = true;
+        }
+    }
+}
+
+fn rand_id() -> String {
+    thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(16)
+        .map(char::from)
+        .collect()
+}
+
+/// Return the number of energized tiles when the beam enters the top-left corner heading right.
+pub fn part1(map: &Map) -> usize {
+    energized_tiles(Pos::new(0, 0), Direction::Right, map)
+}
+
+/// Return the number of tiles energized by a beam starting at `start` heading in `direction`.
+pub fn energized_tiles(start: Pos, direction: Direction, map: &Map) -> usize {
+    let mut beams: VecDeque<Beam> = VecDeque::new();
+    beams.push_back(Beam::new(start, map[start], direction));
+
+    let mut splits: HashSet<Pos> = HashSet::new();
+    let mut energized_tiles: HashSet<Pos> = HashSet::new();
+    // A beam that goes where a beam already went would follow it again, possibly round a loop.
+    let mut travelled: HashSet<(Pos, Direction)> = HashSet::new();
+
+    while !beams.is_empty() {
+        let mut beam = beams.pop_front().unwrap();
+        loop {
+            if !travelled.insert((beam.pos, beam.direction)) {
+                beam.completed = true;
+            } else {
+                beam.update(&mut beams, &mut splits, map);
+            }
+            if beam.completed {
+                energized_tiles.extend(beam.seen.iter());
+                break;
+            }
+        }
+    }
+    energized_tiles.len()
+}
+
+/// Return the largest number of energized tiles over all beams entering from the edges.
+pub fn part2(map: &Map) -> usize {
+    let (nrow, ncol) = (map.nrow(), map.ncol());
+
+    let mut num_energized: Vec<usize> = Vec::new();
+    for row in 0..nrow {
+        num_energized.push(energized_tiles(Pos::new(row, 0), Direction::Right, map));
+        num_energized.push(energized_tiles(
+            Pos::new(row, ncol - 1),
+            Direction::Left,
+            map,
+        ));
+    }
+    for col in 0..ncol {
+        num_energized.push(energized_tiles(Pos::new(0, col), Direction::Down, map));
+        num_energized.push(energized_tiles(Pos::new(nrow - 1, col), Direction::Up, map));
+    }
+    *num_energized.iter().max().unwrap()
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl fmt::Display, SolveError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl fmt::Display, SolveError> {
+        Ok(part2(input))
+    }
+}
+
+/// Parse the contraption layout.
+pub fn parse(input: &str) -> Result<Map, ParseError> {
+    Grid::parse(input, Tile::new).map_err(|e| e.in_text(input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn looping_beam() {
+        // The beam split at the centre goes round the mirrors back into the splitter, which
+        // sends it round again.
+        let map = parse("./\\\n.|/\n...\n").unwrap();
+        assert_eq!(energized_tiles(Pos::new(1, 1), Direction::Right, &map), 5);
+    }
+}
@@ -0,0 +1,270 @@
+use common::parse::{number, split_once, ParseError};
+use common::{Solution, SolveError};
+use std::fmt::Display;
+
+/// Parse the game records.
+pub fn parse(input: &str) -> Result<Vec<Record>, ParseError> {
+    input.lines().map(Record::from_line).collect()
+}
+
+/// Return the sum of the ids of the games that are possible with the reference bag.
+pub fn part1(records: &[Record]) -> u64 {
+    get_possible_games_sum(records)
+}
+
+/// Return the sum of the powers of the minimum sets of cubes of each game.
+pub fn part2(records: &[Record]) -> u64 {
+    get_sum_of_powers(records)
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    type Input = Vec<Record>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, SolveError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, SolveError> {
+        Ok(part2(input))
+    }
+}
+
+/// The record of a game: its id and the sets of cubes revealed.
+#[derive(Debug, PartialEq)]
+pub struct Record {
+    pub id: u64,
+    pub sets: Vec<Game>,
+}
+
+impl Record {
+    /// Parse a record such as 'Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green'.
+    pub fn from_line(line: &str) -> Result<Self, ParseError> {
+        Ok(Record {
+            id: get_game_id(line)?,
+            sets: get_game_sets(line)?
+                .into_iter()
+                .map(Game::from_set)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Game {
+    pub red: u64,
+    pub green: u64,
+    pub blue: u64,
+}
+
+impl Game {
+    /// Create a game object from a string set, _e.g._ ' 3 blue, 4 red', ' 3 green, 4 blue, 1 red'.
+    pub fn from_set(set: &str) -> Result<Self, ParseError> {
+        let mut game = Game {
+            red: 0,
+            green: 0,
+            blue: 0,
+        };
+        for cube in set.split(',') {
+            let (n, col) = split_once(cube.trim(), " ")?;
+            let count = match col {
+                "red" => &mut game.red,
+                "green" => &mut game.green,
+                "blue" => &mut game.blue,
+                _ => return Err(ParseError::new(col, "expected red, green or blue")),
+            };
+            *count = number(n)?;
+        }
+        Ok(game)
+    }
+
+    /// Return true if the game is possible given the `bag`.
+    pub fn possible(&self, bag: &Game) -> bool {
+        self.red <= bag.red && self.green <= bag.green && self.blue <= bag.blue
+    }
+
+    /// Return the power of the cubes in the set, defined as the product of all cubes.
+    pub fn power(&self) -> u64 {
+        self.red * self.green * self.blue
+    }
+}
+
+fn get_game_id(game: &str) -> Result<u64, ParseError> {
+    let (game_id, _) = split_once(game, ":")?;
+    match game_id.split_once(' ') {
+        Some(("Game", n)) => number(n),
+        _ => Err(ParseError::new(game_id, "expected \"Game <id>\"")),
+    }
+}
+
+fn get_game_sets(game: &str) -> Result<Vec<&str>, ParseError> {
+    let (_, game_sets) = split_once(game, ":")?;
+    Ok(game_sets.split(';').collect())
+}
+
+fn all_game_sets_possible(game_sets: &[Game], bag: &Game) -> bool {
+    game_sets.iter().all(|gs| gs.possible(bag))
+}
+
+pub fn get_possible_games_sum(records: &[Record]) -> u64 {
+    let ref_game = get_game_bag();
+    records
+        .iter()
+        .filter(|record| all_game_sets_possible(&record.sets, &ref_game))
+        .map(|record| record.id)
+        .sum()
+}
+
+pub fn get_sum_of_powers(records: &[Record]) -> u64 {
+    records
+        .iter()
+        .map(|record| get_min_required_cubes(&record.sets).power())
+        .sum()
+}
+
+/// Return a game containing the minimum number of cubes of each color that would make all game
+/// sets possible.
+fn get_min_required_cubes(game_set: &[Game]) -> Game {
+    let min_req_red = game_set.iter().map(|g| g.red).max();
+    let min_req_green = game_set.iter().map(|g| g.green).max();
+    let min_req_blue = game_set.iter().map(|g| g.blue).max();
+    Game {
+        red: min_req_red.unwrap_or(0),
+        green: min_req_green.unwrap_or(0),
+        blue: min_req_blue.unwrap_or(0),
+    }
+}
+
+/// Return the reference game bag.
+fn get_game_bag() -> Game {
+    Game {
+        red: 12,
+        green: 13,
+        blue: 14,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn game_ids() {
+        assert_eq!(
+            get_game_id("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green"),
+            Ok(1)
+        );
+        assert_eq!(
+            get_game_id("Game 12: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"),
+            Ok(12)
+        );
+    }
+
+    #[test]
+    fn game_sets() {
+        assert_eq!(
+            get_game_sets("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green"),
+            Ok(vec![
+                " 3 blue, 4 red",
+                " 1 red, 2 green, 6 blue",
+                " 2 green"
+            ])
+        );
+        assert_eq!(
+            get_game_sets("Game 12: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"),
+            Ok(vec![" 6 red, 1 blue, 3 green", " 2 blue, 1 red, 2 green"])
+        );
+    }
+
+    #[test]
+    fn game_from_set() {
+        assert_eq!(
+            Game::from_set(" 3 blue, 4 red"),
+            Ok(Game {
+                red: 4,
+                green: 0,
+                blue: 3,
+            })
+        );
+        assert_eq!(
+            Game::from_set(" 6 red, 1 blue, 3 green"),
+            Ok(Game {
+                red: 6,
+                green: 3,
+                blue: 1,
+            })
+        );
+        assert_eq!(
+            Game::from_set(" 3 green"),
+            Ok(Game {
+                red: 0,
+                green: 3,
+                blue: 0,
+            })
+        );
+    }
+
+    #[test]
+    fn possible_games() {
+        let ref_game = get_game_bag();
+        assert!(all_game_sets_possible(
+            &Record::from_line("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green")
+                .unwrap()
+                .sets,
+            &ref_game
+        ));
+        assert!(all_game_sets_possible(
+            &Record::from_line("Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue")
+                .unwrap()
+                .sets,
+            &ref_game
+        ));
+        assert!(all_game_sets_possible(
+            &Record::from_line("Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green")
+                .unwrap()
+                .sets,
+            &ref_game
+        ));
+    }
+
+    #[test]
+    fn impossible_games() {
+        let ref_game = get_game_bag();
+        assert!(!all_game_sets_possible(
+            &Record::from_line(
+                "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red"
+            )
+            .unwrap()
+            .sets,
+            &ref_game
+        ));
+        assert!(!all_game_sets_possible(
+            &Record::from_line(
+                "Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red"
+            )
+            .unwrap()
+            .sets,
+            &ref_game
+        ));
+    }
+
+    #[test]
+    fn malformed_records() {
+        assert_eq!(
+            Game::from_set(" 3 blue, 4 purple").unwrap_err().message(),
+            "expected red, green or blue"
+        );
+        assert!(Game::from_set(" x red").is_err());
+        assert!(Record::from_line("Game 1 3 blue").is_err());
+        let input = "Game 1: 3 blue\nGame two: 1 red\n";
+        let err = parse(input).unwrap_err().locate(input);
+        let loc = err.location().unwrap();
+        assert_eq!((loc.line, loc.column, loc.width), (2, 6, 3));
+    }
+}
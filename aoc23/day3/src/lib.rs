@@ -0,0 +1,172 @@
+use common::grid::{Grid, Pos};
+use common::parse::{number, ParseError};
+use common::{Solution, SolveError};
+use std::fmt::Display;
+
+/// Parse the engine schematic into its numbers and symbols.
+pub fn parse(input: &str) -> Result<(Vec<Number>, Vec<Symbol>), ParseError> {
+    let schematic = Grid::bytes(input).map_err(|e| e.in_text(input))?;
+    let mut numbers: Vec<Number> = Vec::new();
+    let mut symbols: Vec<Symbol> = Vec::new();
+
+    for (row, (line, text)) in schematic.rows().zip(input.lines()).enumerate() {
+        let mut col = 0;
+        while col < line.len() {
+            let ch = line[col];
+            if ch.is_ascii_digit() {
+                let len = line[col..]
+                    .iter()
+                    .take_while(|b| b.is_ascii_digit())
+                    .count();
+                numbers.push(Number {
+                    val: number(&text[col..col + len])?,
+                    loc: NumberLoc {
+                        row,
+                        cols: (col, col + len - 1),
+                    },
+                });
+                col += len;
+                continue;
+            }
+            if ch != b'.' {
+                symbols.push(Symbol {
+                    val: ch as char,
+                    loc: Pos::new(row, col),
+                });
+            }
+            col += 1;
+        }
+    }
+
+    Ok((numbers, symbols))
+}
+
+/// Return the sum of the part numbers, _i.e._ numbers adjacent to a symbol.
+pub fn part1((nums, syms): &(Vec<Number>, Vec<Symbol>)) -> u64 {
+    sum_part_nums(nums, syms)
+}
+
+/// Return the sum of the gear ratios.
+pub fn part2((nums, syms): &(Vec<Number>, Vec<Symbol>)) -> u64 {
+    sum_gear_ratios(nums, syms)
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    type Input = (Vec<Number>, Vec<Symbol>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, SolveError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, SolveError> {
+        Ok(part2(input))
+    }
+}
+
+pub fn sum_part_nums(numbers: &[Number], symbols: &[Symbol]) -> u64 {
+    numbers
+        .iter()
+        .filter(|num| symbols.iter().any(|sym| num.adjacent(sym)))
+        .map(|num| num.val)
+        .sum()
+}
+
+pub fn sum_gear_ratios(numbers: &[Number], symbols: &[Symbol]) -> u64 {
+    let mut total = 0;
+    symbols.iter().for_each(|s| {
+        if s.val == '*' {
+            let part_nums = numbers
+                .iter()
+                .filter(|n| n.adjacent(s))
+                .map(|n| n.val)
+                .collect::<Vec<u64>>();
+            if part_nums.len() == 2 {
+                total += part_nums[0] * part_nums[1];
+            }
+        }
+    });
+    total
+}
+
+#[derive(Debug, PartialEq)]
+struct NumberLoc {
+    row: usize,
+    cols: (usize, usize),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Number {
+    val: u64,
+    loc: NumberLoc,
+}
+
+impl Number {
+    fn adjacent(&self, sym: &Symbol) -> bool {
+        let col_range = self.loc.cols.0.saturating_sub(1)..=self.loc.cols.1 + 1;
+        self.loc.row.abs_diff(sym.loc.row) <= 1 && col_range.contains(&sym.loc.col)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Symbol {
+    val: char,
+    loc: Pos,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adjacency() {
+        let num = Number {
+            val: 592,
+            loc: NumberLoc {
+                row: 6,
+                cols: (2, 4),
+            },
+        };
+        let sym_1 = Symbol {
+            val: '+',
+            loc: Pos::new(5, 5),
+        };
+        let sym_2 = Symbol {
+            val: '+',
+            loc: Pos::new(5, 1),
+        };
+        let sym_3 = Symbol {
+            val: '+',
+            loc: Pos::new(7, 5),
+        };
+        let sym_4 = Symbol {
+            val: '+',
+            loc: Pos::new(7, 1),
+        };
+        let sym_5 = Symbol {
+            val: '+',
+            loc: Pos::new(6, 1),
+        };
+        let sym_6 = Symbol {
+            val: '+',
+            loc: Pos::new(6, 5),
+        };
+        let sym_7 = Symbol {
+            val: '+',
+            loc: Pos::new(8, 3),
+        };
+        assert!(num.adjacent(&sym_1));
+        assert!(num.adjacent(&sym_2));
+        assert!(num.adjacent(&sym_3));
+        assert!(num.adjacent(&sym_4));
+        assert!(num.adjacent(&sym_5));
+        assert!(num.adjacent(&sym_6));
+        assert!(!num.adjacent(&sym_7));
+    }
+}
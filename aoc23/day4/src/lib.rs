@@ -0,0 +1,177 @@
+use common::parse::{number, split_once, ParseError};
+use common::{Solution, SolveError};
+use std::collections::HashSet;
+use std::fmt::Display;
+
+/// Parse the scratchcards.
+pub fn parse(input: &str) -> Result<Vec<Card>, ParseError> {
+    input.lines().map(Card::from_str).collect()
+}
+
+/// Return the total points of the scratchcards.
+pub fn part1(cards: &[Card]) -> u64 {
+    sum_winning_points(cards)
+}
+
+/// Return the total number of scratchcards, originals and won copies.
+pub fn part2(cards: &[Card]) -> u64 {
+    total_scratchcards(cards)
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    type Input = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, SolveError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, SolveError> {
+        Ok(part2(input))
+    }
+}
+
+/// Return the total number of scratchcards, where copies won past the last card are not counted.
+pub fn total_scratchcards(cards: &[Card]) -> u64 {
+    let mut card_copies: Vec<u64> = vec![1; cards.len()];
+    let mut total = 0;
+    for (i, curr_card) in cards.iter().enumerate() {
+        let last = (i + curr_card.matching as usize).min(cards.len() - 1);
+        for j in (i + 1)..=last {
+            card_copies[j] += card_copies[i];
+        }
+        total += card_copies[i];
+    }
+    total
+}
+
+pub fn sum_winning_points(cards: &[Card]) -> u64 {
+    cards.iter().map(|c| c.points()).sum()
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Card {
+    id: u64,
+    win: HashSet<u64>,
+    own: HashSet<u64>,
+    matching: u64,
+}
+
+impl Card {
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let (card_s, numbers) = split_once(s, ":")?;
+        let id = match card_s.strip_prefix("Card") {
+            Some(id) => number(id.trim())?,
+            None => return Err(ParseError::new(card_s, "expected \"Card <id>\"")),
+        };
+        let (win_s, own_s) = split_once(numbers, "|")?;
+        let win = str_nums_to_set(win_s)?;
+        let own = str_nums_to_set(own_s)?;
+        let matching: usize = win.intersection(&own).count();
+        Ok(Card {
+            id,
+            win,
+            own,
+            matching: matching as u64,
+        })
+    }
+
+    fn points(&self) -> u64 {
+        let nums = self.win.intersection(&self.own).count() as u32;
+        match nums {
+            1 => 1,
+            2.. => (2u64).pow(nums - 1),
+            _ => 0,
+        }
+    }
+}
+
+fn str_nums_to_set(s: &str) -> Result<HashSet<u64>, ParseError> {
+    s.split_whitespace().map(number).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_nums_from_str() {
+        assert_eq!(
+            str_nums_to_set("   1 48 83 86 17 ").unwrap(),
+            HashSet::from_iter(vec![1, 48, 83, 86, 17])
+        );
+    }
+
+    #[test]
+    fn parse_card_from_str() {
+        assert_eq!(
+            Card::from_str("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53").unwrap(),
+            Card {
+                id: 1,
+                win: HashSet::from_iter(vec![41, 48, 83, 86, 17]),
+                own: HashSet::from_iter(vec![83, 86, 6, 31, 17, 9, 48, 53]),
+                matching: 4,
+            }
+        );
+        assert_eq!(
+            Card::from_str("Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19").unwrap(),
+            Card {
+                id: 2,
+                win: HashSet::from_iter(vec![13, 32, 20, 16, 61]),
+                own: HashSet::from_iter(vec![61, 30, 68, 82, 17, 32, 24, 19]),
+                matching: 2,
+            }
+        );
+        assert_eq!(
+            Card::from_str("Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1").unwrap(),
+            Card {
+                id: 3,
+                win: HashSet::from_iter(vec![1, 21, 53, 59, 44]),
+                own: HashSet::from_iter(vec![69, 82, 63, 72, 16, 21, 14, 1]),
+                matching: 2,
+            }
+        );
+        assert_eq!(
+            Card::from_str("Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83").unwrap(),
+            Card {
+                id: 4,
+                win: HashSet::from_iter(vec![41, 92, 73, 84, 69]),
+                own: HashSet::from_iter(vec![59, 84, 76, 51, 58, 5, 54, 83]),
+                matching: 1,
+            }
+        );
+        assert_eq!(
+            Card::from_str("Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36").unwrap(),
+            Card {
+                id: 5,
+                win: HashSet::from_iter(vec![87, 83, 26, 28, 32]),
+                own: HashSet::from_iter(vec![88, 30, 70, 12, 93, 22, 82, 36]),
+                matching: 0,
+            }
+        );
+        assert_eq!(
+            Card::from_str("Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11").unwrap(),
+            Card {
+                id: 6,
+                win: HashSet::from_iter(vec![31, 18, 13, 56, 72]),
+                own: HashSet::from_iter(vec![74, 77, 10, 23, 35, 67, 36, 11]),
+                matching: 0,
+            }
+        );
+        assert_eq!(1 + 1, 2);
+    }
+
+    #[test]
+    fn copies_past_the_last_card() {
+        let cards = parse("Card 1: 1 2 | 1 2\nCard 2: 3 | 3\n").unwrap();
+        assert_eq!(total_scratchcards(&cards), 1 + 2);
+        let cards = parse("Card 1: 1 2 | 1 2\n").unwrap();
+        assert_eq!(total_scratchcards(&cards), 1);
+    }
+}
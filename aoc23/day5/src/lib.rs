@@ -0,0 +1,219 @@
+use common::interval::{IntervalSet, OffsetMap};
+use common::parse::{numbers, split_once, ParseError};
+use common::{Solution, SolveError};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::ops::Range;
+
+/// The query seeds, the query seeds read as ranges, and the map names to their maps.
+pub type Almanac = (Vec<u64>, IntervalSet, HashMap<&'static str, OffsetMap>);
+
+/// The names of the maps, from seeds to locations.
+const MAP_NAMES: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+/// Return the lowest location number of the query seeds.
+pub fn part1((seeds, _, maps): &Almanac) -> u64 {
+    find_min_location(seeds, maps)
+}
+
+/// Return the lowest location number of the query seed ranges.
+pub fn part2((_, seed_ranges, maps): &Almanac) -> u64 {
+    find_min_location_for_ranges(seed_ranges, maps)
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    type Input = Almanac;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, SolveError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, SolveError> {
+        Ok(part2(input))
+    }
+}
+
+fn seed_location(seed_id: u64, maps: &HashMap<&str, OffsetMap>) -> u64 {
+    MAP_NAMES
+        .iter()
+        .fold(seed_id, |id, name| maps[name].get(id))
+}
+
+/// Return the minimum location id for the query seeds, which must not be empty.
+pub fn find_min_location(seeds: &[u64], maps: &HashMap<&str, OffsetMap>) -> u64 {
+    seeds
+        .iter()
+        .map(|s| seed_location(*s, maps))
+        .min()
+        .expect("no query seeds")
+}
+
+/// Return the minimum location id for a set of query seed ranges, which must not be empty.
+pub fn find_min_location_for_ranges(
+    range_queries: &IntervalSet,
+    maps: &HashMap<&str, OffsetMap>,
+) -> u64 {
+    MAP_NAMES
+        .iter()
+        .fold(range_queries.clone(), |ids, name| maps[name].map_set(&ids))
+        .min()
+        .expect("no query seed ranges")
+}
+
+/// Return the query seeds `seeds`, parsed from `seeds_list`, read as pairs of range start and
+/// length, or an error located in `seeds_list` about the first pair that is not a range.
+fn get_seed_ranges(seeds_list: &str, seeds: &[u64]) -> Result<IntervalSet, ParseError> {
+    let words: Vec<&str> = seeds_list.split_whitespace().collect();
+    words
+        .chunks(2)
+        .zip(seeds.chunks(2))
+        .map(|(words, pair)| match *pair {
+            [_, 0] => Err(ParseError::new(words[1], "empty seed range")),
+            [start, len] => start
+                .checked_add(len)
+                .map(|end| start..end)
+                .ok_or_else(|| ParseError::new(words[1], "seed range ends past u64")),
+            _ => Err(ParseError::new(words[0], "seed range without a length")),
+        })
+        .collect()
+}
+
+/// Parse input data and return a vector of query seeds, the seeds as ranges, and a map of map
+/// names to their maps.
+pub fn parse(input: &str) -> Result<Almanac, ParseError> {
+    let mut lines = input.lines();
+
+    // read seeds
+    let seeds_header = lines.next().unwrap_or_default();
+    let seeds_list = split_once(seeds_header, ":")?.1;
+    let seeds: Vec<u64> = numbers(seeds_list)?;
+    if seeds.is_empty() {
+        return Err(ParseError::new(seeds_header, "no seeds"));
+    }
+    let seed_ranges = get_seed_ranges(seeds_list, &seeds)?;
+
+    // read maps
+    let mut maps: HashMap<&'static str, OffsetMap> = MAP_NAMES
+        .iter()
+        .map(|&name| (name, OffsetMap::new()))
+        .collect();
+
+    let mut cur_name = MAP_NAMES[0];
+    for row in lines {
+        if row.is_empty() {
+            continue;
+        } else if let Some(header) = row.strip_suffix(" map:") {
+            cur_name = MAP_NAMES
+                .iter()
+                .find(|&&name| name == header)
+                .ok_or_else(|| ParseError::new(header, "unknown map"))?;
+        } else {
+            let data: Vec<u64> = numbers(row)?;
+            if data.len() != 3 {
+                return Err(ParseError::new(
+                    row,
+                    format!("expected 3 numbers, found {}", data.len()),
+                ));
+            }
+            // destination range start is first (e.g. soil in seed-to-soil map), then the source
+            // range start (e.g. seed in seed-to-soil map)
+            let src_range = Range {
+                start: data[1],
+                end: data[1]
+                    .checked_add(data[2])
+                    .ok_or_else(|| ParseError::new(row, "source range ends past u64"))?,
+            };
+            maps.get_mut(cur_name)
+                .unwrap()
+                .insert(src_range, data[0])
+                .map_err(|e| ParseError::new(row, e.to_string()))?;
+        };
+    }
+
+    Ok((seeds, seed_ranges, maps))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seed_to_soil() -> HashMap<&'static str, OffsetMap> {
+        let mut map = OffsetMap::new();
+        map.insert(98..100, 50).unwrap();
+        map.insert(50..98, 52).unwrap();
+        let mut maps: HashMap<&str, OffsetMap> =
+            MAP_NAMES.iter().map(|&n| (n, OffsetMap::new())).collect();
+        maps.insert("seed-to-soil", map);
+        maps
+    }
+
+    #[test]
+    fn seed_ranges() {
+        assert_eq!(
+            get_seed_ranges("79 14 55 13", &[79, 14, 55, 13]),
+            Ok(IntervalSet::from_iter([55..68, 79..93]))
+        );
+    }
+
+    #[test]
+    fn invalid_seeds() {
+        let seeds = [u64::MAX - 1, 2].map(|n| n.to_string()).join(" ");
+        for (input, message, column) in [
+            ("seeds:\n", "no seeds", 1),
+            ("seeds: 1 2 3\n", "seed range without a length", 12),
+            ("seeds: 5 0\n", "empty seed range", 10),
+            (
+                &format!("seeds: {}\n", seeds),
+                "seed range ends past u64",
+                29,
+            ),
+            (
+                &format!("seeds: 1 2\n\nseed-to-soil map:\n0 {}\n", seeds),
+                "source range ends past u64",
+                1,
+            ),
+        ] {
+            let err = parse(input).unwrap_err().locate(input);
+            assert_eq!(err.message(), message, "{:?}", input);
+            assert_eq!(
+                err.location().map(|loc| loc.column),
+                Some(column),
+                "{:?}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn map_ids() {
+        let maps = seed_to_soil();
+        assert_eq!(seed_location(79, &maps), 81);
+        assert_eq!(seed_location(14, &maps), 14);
+        assert_eq!(seed_location(99, &maps), 51);
+        assert_eq!(find_min_location(&[79, 14, 55, 13], &maps), 13);
+    }
+
+    #[test]
+    fn map_ranges() {
+        let maps = seed_to_soil();
+        let queries = IntervalSet::from_iter([45..52, 96..100]);
+        assert_eq!(find_min_location_for_ranges(&queries, &maps), 45);
+        let queries = IntervalSet::from(96..100);
+        assert_eq!(find_min_location_for_ranges(&queries, &maps), 50);
+    }
+}
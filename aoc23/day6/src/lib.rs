@@ -0,0 +1,212 @@
+use common::math::isqrt;
+use common::parse::{numbers, split_once, ParseError};
+use common::{Solution, SolveError};
+use std::fmt::Display;
+
+/// Return the product of the number of ways to win each race.
+pub fn part1((times, dists): &(Vec<i64>, Vec<i64>)) -> Result<i64, SolveError> {
+    get_num_winning_all_races_analytically(times, dists).ok_or_else(overflow)
+}
+
+/// Return the number of ways to win the single race with the numbers concatenated, or an error if
+/// they or the count do not fit in an i64.
+pub fn part2((times, dists): &(Vec<i64>, Vec<i64>)) -> Result<i64, SolveError> {
+    get_num_winning_big_race_analytically(times, dists)
+}
+
+fn overflow() -> SolveError {
+    SolveError::new("the number of ways to win overflows i64")
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    type Input = (Vec<i64>, Vec<i64>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, SolveError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, SolveError> {
+        part2(input)
+    }
+}
+
+pub fn get_num_winning_big_race_analytically(
+    times: &[i64],
+    dists: &[i64],
+) -> Result<i64, SolveError> {
+    let t =
+        join_digits(times).ok_or_else(|| SolveError::new("the times do not join into an i64"))?;
+    let d = join_digits(dists)
+        .ok_or_else(|| SolveError::new("the distances do not join into an i64"))?;
+
+    get_num_winning_analytically(&t, &d).ok_or_else(overflow)
+}
+
+/// Return the number written with the digits of `nums` one after the other, or None if one of
+/// them is negative or the result overflows i64.
+fn join_digits(nums: &[i64]) -> Option<i64> {
+    nums.iter().try_fold(0i64, |joined, &n| {
+        if n < 0 {
+            return None;
+        }
+        let digits = n.checked_ilog10().map_or(1, |log| log + 1);
+        joined
+            .checked_mul(10i64.checked_pow(digits)?)?
+            .checked_add(n)
+    })
+}
+
+// fn get_num_winning_all_races(times: Vec<i64>, dists: Vec<i64>) -> i64 {
+//     times
+//         .iter()
+//         .zip(&dists)
+//         .map(|(t, d)| get_num_winning(t, d))
+//         .product()
+// }
+
+pub fn get_num_winning_all_races_analytically(times: &[i64], dists: &[i64]) -> Option<i64> {
+    times.iter().zip(dists).try_fold(1i64, |product, (t, d)| {
+        product.checked_mul(get_num_winning_analytically(t, d)?)
+    })
+}
+
+// fn get_num_winning(t: &i64, d: &i64) -> i64 {
+//     (0..=*t).filter(|i| (t - i) * i > *d).count() as i64
+// }
+
+/// Return the number of hold times `h` in `0..=t` that beat the record, _i.e._ with
+/// `h * (t - h) > d`, or None if it overflows i64. They lie strictly between the roots
+/// `(t ± sqrt(t² - 4d)) / 2`, which are found with an exact integer square root. The computation
+/// is done in i128, where the squares of i64 values fit.
+fn get_num_winning_analytically(t: &i64, d: &i64) -> Option<i64> {
+    let (t, d) = (i128::from(*t), i128::from(*d));
+    if t < 0 {
+        return Some(0);
+    }
+    let disc = t.checked_mul(t)?.checked_sub(d.checked_mul(4)?)?;
+    if disc <= 0 {
+        return Some(0);
+    }
+    let beats = |h: i128| h * (t - h) > d;
+    // The integer root is within one of the real one, so the first winning hold time is close.
+    let mut x1 = ((t - isqrt(disc as u128) as i128) / 2).max(0);
+    while x1 > 0 && beats(x1 - 1) {
+        x1 -= 1;
+    }
+    while x1 <= t / 2 && !beats(x1) {
+        x1 += 1;
+    }
+    // The winning hold times are symmetric around t / 2.
+    let x2 = t - x1;
+    i64::try_from((x2 - x1 + 1).max(0)).ok()
+}
+
+/// Parse input data and return a tuple of times and distances vectors, with at least one race.
+pub fn parse(input: &str) -> Result<(Vec<i64>, Vec<i64>), ParseError> {
+    let mut lines = input.lines();
+    let mut next_line = |name| {
+        lines
+            .next()
+            .ok_or_else(|| ParseError::new(&input[input.len()..], format!("missing {}", name)))
+    };
+
+    let times_str = next_line("times")?;
+    let times: Vec<i64> = parse_nums(times_str)?;
+    if times.is_empty() {
+        return Err(ParseError::new(times_str, "no races"));
+    }
+
+    let dists_str = next_line("distances")?;
+    let dists: Vec<i64> = parse_nums(dists_str)?;
+
+    if dists.len() != times.len() {
+        return Err(ParseError::new(
+            dists_str,
+            format!("expected {} distances, found {}", times.len(), dists.len()),
+        ));
+    }
+    Ok((times, dists))
+}
+
+/// Return a vector of parsed number from string.
+/// The numbers occur after ':' in `num_str`.
+fn parse_nums(num_str: &str) -> Result<Vec<i64>, ParseError> {
+    numbers(split_once(num_str, ":")?.1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_parse_nums() {
+        assert_eq!(parse_nums("Time:      7  15   30"), Ok(vec![7, 15, 30]));
+        assert_eq!(parse_nums("Distance:  9  40  200"), Ok(vec![9, 40, 200]));
+    }
+
+    #[test]
+    fn malformed_document() {
+        let err = parse("Time: 7 15\n").unwrap_err();
+        assert_eq!(err.message(), "missing distances");
+        let err = parse("Time: 7 15\nDistance: 9\n").unwrap_err();
+        assert_eq!(err.message(), "expected 2 distances, found 1");
+        let err = parse("Time:\nDistance:\n").unwrap_err();
+        assert_eq!(err.message(), "no races");
+    }
+
+    #[test]
+    fn part1_get_num_winning() {
+        assert_eq!(get_num_winning_analytically(&7, &9), Some(4));
+        assert_eq!(get_num_winning_analytically(&15, &40), Some(8));
+        assert_eq!(get_num_winning_analytically(&30, &200), Some(9));
+    }
+
+    #[test]
+    fn part1_get_num_winning_analytically() {
+        assert_eq!(get_num_winning_analytically(&7, &9), Some(4));
+        assert_eq!(get_num_winning_analytically(&15, &40), Some(8));
+        assert_eq!(get_num_winning_analytically(&30, &200), Some(9));
+        assert_eq!(get_num_winning_analytically(&71530, &940200), Some(71503));
+    }
+
+    #[test]
+    fn long_races() {
+        let races = parse("Time: 99999 99999\nDistance: 1 1\n").unwrap();
+        assert_eq!(part1(&races), Ok(99998 * 99998));
+        assert_eq!(part2(&races), Ok(9999999998));
+        assert_eq!(
+            get_num_winning_analytically(&i64::MAX, &0),
+            Some(i64::MAX - 1)
+        );
+        assert_eq!(get_num_winning_analytically(&i64::MAX, &-1), None);
+
+        let races = parse("Time: 9999999999 9999999999\nDistance: 1 1\n").unwrap();
+        let err = part2(&races).unwrap_err();
+        assert_eq!(err.message(), "the times do not join into an i64");
+        assert_eq!(join_digits(&[7, 15, 30, 0]), Some(715300));
+        assert_eq!(join_digits(&[-7]), None);
+    }
+
+    #[test]
+    fn get_num_winning_exact() {
+        for t in 0..60 {
+            for d in 0..t * t / 4 + 2 {
+                let brute = (0..=t).filter(|h| h * (t - h) > d).count() as i64;
+                assert_eq!(
+                    get_num_winning_analytically(&t, &d),
+                    Some(brute),
+                    "t={} d={}",
+                    t,
+                    d
+                );
+            }
+        }
+    }
+}
@@ -1,6 +1,8 @@
+use common::parse::ParseError;
 use std::cmp::Ordering;
 use std::collections::{HashMap, HashSet};
 use std::fmt::{Debug, Display, Formatter, Result};
+use std::str::FromStr;
 
 #[derive(Ord, Eq, PartialOrd, PartialEq, Hash, Clone, Copy)]
 pub enum Card {
@@ -38,6 +40,16 @@ impl Card {
             _ => None,
         }
     }
+
+    /// Return the strength of the card when `J` is a jack, ranked between `T` and `Q`, rather
+    /// than the weakest card as a joker.
+    pub fn jack_strength(&self) -> u8 {
+        match self {
+            Card::J => Card::T as u8 + 1,
+            Card::Q | Card::K | Card::A => *self as u8 + 1,
+            _ => *self as u8,
+        }
+    }
 }
 
 impl Debug for Card {
@@ -74,15 +86,20 @@ pub struct Hand {
     pub bid: u64,
 }
 
-impl Hand {
-    pub fn from_str(s: &str) -> Option<Self> {
+impl FromStr for Hand {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
         let bytes = s.as_bytes();
         let char_set: HashSet<&u8> = HashSet::from_iter("AKQJT98765432".as_bytes());
         let chars_got: HashSet<&u8> = HashSet::from_iter(bytes);
-        if !chars_got.is_subset(&char_set) && bytes.len() != 5 {
-            return None;
+        if !chars_got.is_subset(&char_set) || bytes.len() != 5 {
+            return Err(ParseError::new(
+                s,
+                "expected a hand of 5 cards among AKQJT98765432",
+            ));
         }
-        Some(Hand {
+        Ok(Hand {
             cards: (
                 Card::from_byte(bytes[0]).unwrap(),
                 Card::from_byte(bytes[1]).unwrap(),
@@ -95,7 +112,9 @@ impl Hand {
             bid: 0,
         })
     }
+}
 
+impl Hand {
     // pub fn set_rank(&mut self, r: u64) {
     //     self.rank = r;
     // }
@@ -104,6 +123,30 @@ impl Hand {
         self.bid = b;
     }
 
+    /// Return the cards of the hand in order.
+    pub fn card_array(&self) -> [Card; 5] {
+        [
+            self.cards.0,
+            self.cards.1,
+            self.cards.2,
+            self.cards.3,
+            self.cards.4,
+        ]
+    }
+
+    /// Return the sort key of the hand when `J` cards are jacks rather than jokers.
+    pub fn jacks_key(&self) -> (HandType, [u8; 5]) {
+        let mut freq: HashMap<Card, u32> = HashMap::new();
+        for card in self.card_array() {
+            *freq.entry(card).or_insert(0) += 1;
+        }
+        let counts = freq.values().copied().collect::<Vec<u32>>();
+        (
+            Self::classify(&counts),
+            self.card_array().map(|c| c.jack_strength()),
+        )
+    }
+
     fn get_type(s: &str) -> HandType {
         let mut freq: HashMap<Card, u32> = HashMap::new();
         for b in s.as_bytes() {
@@ -123,9 +166,9 @@ impl Hand {
                     // if the counts are equal
                     if a.1 == b.1 {
                         // compare the cards
-                        a.0.cmp(&b.0)
+                        a.0.cmp(b.0)
                     } else {
-                        a.1.cmp(&b.1)
+                        a.1.cmp(b.1)
                     }
                 })
                 .map(|(k, _v)| k)
@@ -139,25 +182,30 @@ impl Hand {
                 }
             }
 
-            match new_freq.len() {
-                1 => HandType::FiveOfAKind,
-                2 => {
-                    if new_freq.values().any(|v| v == &4_u32) {
-                        HandType::FourOfAKind
-                    } else {
-                        HandType::FullHouse
-                    }
+            Self::classify(&new_freq.values().copied().collect::<Vec<u32>>())
+        }
+    }
+
+    /// Return the hand type given the count of each distinct card in the hand.
+    fn classify(counts: &[u32]) -> HandType {
+        match counts.len() {
+            1 => HandType::FiveOfAKind,
+            2 => {
+                if counts.contains(&4) {
+                    HandType::FourOfAKind
+                } else {
+                    HandType::FullHouse
                 }
-                3 => {
-                    if new_freq.values().any(|v| v == &3_u32) {
-                        HandType::ThreeOfAKind
-                    } else {
-                        HandType::TwoPair
-                    }
+            }
+            3 => {
+                if counts.contains(&3) {
+                    HandType::ThreeOfAKind
+                } else {
+                    HandType::TwoPair
                 }
-                4 => HandType::OnePair,
-                _ => HandType::HighCard,
             }
+            4 => HandType::OnePair,
+            _ => HandType::HighCard,
         }
     }
 }
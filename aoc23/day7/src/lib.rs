@@ -0,0 +1,160 @@
+pub mod camel;
+
+use camel::Hand;
+use common::parse::{number, split_once, ParseError};
+use common::{Solution, SolveError};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Parse the hands and their bids.
+pub fn parse(input: &str) -> Result<Vec<Hand>, ParseError> {
+    let mut hands: Vec<Hand> = Vec::new();
+    for line in input.lines() {
+        let (hand_str, bit_str) = split_once(line, " ")?;
+        let mut hand = Hand::from_str(hand_str)?;
+        hand.set_bid(number(bit_str)?);
+        hands.push(hand);
+    }
+    Ok(hands)
+}
+
+/// Return the total winnings when `J` cards are jacks.
+pub fn part1(hands: &[Hand]) -> u64 {
+    let mut ranked = hands.iter().collect::<Vec<&Hand>>();
+    ranked.sort_by_key(|h| h.jacks_key());
+    total_winnings(&ranked)
+}
+
+/// Return the total winnings when `J` cards are jokers.
+pub fn part2(hands: &[Hand]) -> u64 {
+    let mut ranked = hands.iter().collect::<Vec<&Hand>>();
+    ranked.sort();
+    total_winnings(&ranked)
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    type Input = Vec<Hand>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, SolveError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, SolveError> {
+        Ok(part2(input))
+    }
+}
+
+/// Return the total winnings of the hands, `ranked` from weakest to strongest.
+pub fn total_winnings(ranked: &[&Hand]) -> u64 {
+    let mut total = 0;
+    for (rank, hand) in ranked.iter().enumerate() {
+        total += (rank + 1) as u64 * hand.bid;
+    }
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use camel::{Card, Hand, HandType};
+    use std::str::FromStr;
+
+    #[test]
+    fn part1_cards_order() {
+        assert!(Card::A > Card::K);
+        assert!(Card::Nine > Card::Six);
+        assert!(Card::J < Card::Two);
+    }
+
+    #[test]
+    fn camel_hand_print() {
+        let hand = Hand {
+            cards: (Card::A, Card::A, Card::K, Card::K, Card::Eight),
+            type_: HandType::TwoPair,
+            rank: 10,
+            bid: 100,
+        };
+        assert_eq!(format!("{}", hand), "AAKK8");
+    }
+
+    #[test]
+    fn camel_hand_reject() {
+        assert!(Hand::from_str("AAAA").is_err());
+        assert!(Hand::from_str("AAAAX").is_err());
+        assert!(Hand::from_str("AAAAAA").is_err());
+    }
+
+    #[test]
+    fn camel_hand_create() {
+        let hand = Hand::from_str("AAAAA").unwrap();
+        assert_eq!(hand.type_, HandType::FiveOfAKind);
+        let hand = Hand::from_str("AA8AA").unwrap();
+        assert_eq!(hand.type_, HandType::FourOfAKind);
+        let hand = Hand::from_str("23332").unwrap();
+        assert_eq!(hand.type_, HandType::FullHouse);
+        let hand = Hand::from_str("TTT98").unwrap();
+        assert_eq!(hand.type_, HandType::ThreeOfAKind);
+        let hand = Hand::from_str("23432").unwrap();
+        assert_eq!(hand.type_, HandType::TwoPair);
+        let hand = Hand::from_str("A23A4").unwrap();
+        assert_eq!(hand.type_, HandType::OnePair);
+        let hand = Hand::from_str("23456").unwrap();
+        assert_eq!(hand.type_, HandType::HighCard);
+    }
+
+    #[test]
+    fn camel_card_compare_diff_type() {
+        let hand_1 = Hand::from_str("AAAAA").unwrap();
+        let hand_2 = Hand::from_str("AA8AA").unwrap();
+        assert!(hand_1 > hand_2);
+
+        let hand_1 = Hand::from_str("23332").unwrap();
+        let hand_2 = Hand::from_str("A23A4").unwrap();
+        assert!(hand_1 > hand_2);
+    }
+
+    #[test]
+    fn camel_card_compare_same_type() {
+        let hand_1 = Hand::from_str("33332").unwrap();
+        let hand_2 = Hand::from_str("2AAAA").unwrap();
+        assert!(hand_1 > hand_2);
+
+        let hand_1 = Hand::from_str("77888").unwrap();
+        let hand_2 = Hand::from_str("77788").unwrap();
+        assert!(hand_1 > hand_2);
+    }
+
+    #[test]
+    fn order_with_joker() {
+        let hand_1 = Hand::from_str("JKKK2").unwrap();
+        let hand_2 = Hand::from_str("QQQQ2").unwrap();
+        assert!(hand_1 < hand_2);
+
+        let hand_1 = Hand::from_str("QJJQ2").unwrap();
+        let hand_2 = Hand::from_str("QQJJ2").unwrap();
+        assert!(hand_1 < hand_2);
+    }
+
+    const HANDS: &str = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+
+    #[test]
+    fn total_winnings_with_jacks() {
+        assert_eq!(part1(&parse(HANDS).unwrap()), 6440);
+    }
+
+    #[test]
+    fn total_winnings_with_jokers() {
+        assert_eq!(part2(&parse(HANDS).unwrap()), 5905);
+    }
+}
@@ -1,112 +1,27 @@
-mod camel;
-
-use camel::Hand;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::path::Path;
-
-fn main() {
-    let mut hands = read_data("./data/input.txt");
-    let winnings = total_winnings(&mut hands);
-    println!("Day 7, Part 2: {}", winnings);
-}
-
-fn total_winnings(hands: &mut [Hand]) -> u64 {
-    hands.sort();
-    let mut total = 0;
-    for (rank, hand) in hands.iter().enumerate() {
-        total += (rank + 1) as u64 * hand.bid;
-    }
-    total
-}
-
-fn read_data(filepath: &str) -> Vec<Hand> {
-    let path = Path::new(filepath);
-    let file = File::open(path).unwrap();
-    let reader = BufReader::new(file);
-
-    let mut hands: Vec<Hand> = Vec::new();
-    for line in reader.lines() {
-        let line_copy = line.unwrap();
-        let (hand_str, bit_str) = line_copy.split_once(' ').unwrap();
-        let mut hand = Hand::from_str(hand_str).unwrap();
-        hand.set_bid(bit_str.parse::<u64>().unwrap());
-        hands.push(hand);
-    }
-    hands
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use camel::{Card, Hand, HandType};
-
-    #[test]
-    fn part1_cards_order() {
-        assert!(Card::A > Card::K);
-        assert!(Card::Nine > Card::Six);
-        assert!(Card::J < Card::Two);
-    }
-
-    #[test]
-    fn camel_hand_print() {
-        let hand = Hand {
-            cards: (Card::A, Card::A, Card::K, Card::K, Card::Eight),
-            type_: HandType::TwoPair,
-            rank: 10,
-            bid: 100,
-        };
-        assert_eq!(format!("{}", hand), "AAKK8");
-    }
-
-    #[test]
-    fn camel_hand_create() {
-        let hand = Hand::from_str("AAAAA").unwrap();
-        assert_eq!(hand.type_, HandType::FiveOfAKind);
-        let hand = Hand::from_str("AA8AA").unwrap();
-        assert_eq!(hand.type_, HandType::FourOfAKind);
-        let hand = Hand::from_str("23332").unwrap();
-        assert_eq!(hand.type_, HandType::FullHouse);
-        let hand = Hand::from_str("TTT98").unwrap();
-        assert_eq!(hand.type_, HandType::ThreeOfAKind);
-        let hand = Hand::from_str("23432").unwrap();
-        assert_eq!(hand.type_, HandType::TwoPair);
-        let hand = Hand::from_str("A23A4").unwrap();
-        assert_eq!(hand.type_, HandType::OnePair);
-        let hand = Hand::from_str("23456").unwrap();
-        assert_eq!(hand.type_, HandType::HighCard);
-    }
-
-    #[test]
-    fn camel_card_compare_diff_type() {
-        let hand_1 = Hand::from_str("AAAAA").unwrap();
-        let hand_2 = Hand::from_str("AA8AA").unwrap();
-        assert!(hand_1 > hand_2);
-
-        let hand_1 = Hand::from_str("23332").unwrap();
-        let hand_2 = Hand::from_str("A23A4").unwrap();
-        assert!(hand_1 > hand_2);
-    }
-
-    #[test]
-    fn camel_card_compare_same_type() {
-        let hand_1 = Hand::from_str("33332").unwrap();
-        let hand_2 = Hand::from_str("2AAAA").unwrap();
-        assert!(hand_1 > hand_2);
-
-        let hand_1 = Hand::from_str("77888").unwrap();
-        let hand_2 = Hand::from_str("77788").unwrap();
-        assert!(hand_1 > hand_2);
-    }
-
-    #[test]
-    fn order_with_joker() {
-        let hand_1 = Hand::from_str("JKKK2").unwrap();
-        let hand_2 = Hand::from_str("QQQQ2").unwrap();
-        assert!(hand_1 < hand_2);
-
-        let hand_1 = Hand::from_str("QJJQ2").unwrap();
-        let hand_2 = Hand::from_str("QQJJ2").unwrap();
-        assert!(hand_1 < hand_2);
-    }
+use common::input::load_file;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let input = match load_file("./data/input.txt") {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let parsed = match day7::parse(&input) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("{}", e.locate(&input).with_file("./data/input.txt"));
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let part1 = day7::part1(&parsed);
+    println!("Day 7, Part 1: {}", part1);
+
+    let part2 = day7::part2(&parsed);
+    println!("Day 7, Part 2: {}", part2);
+
+    ExitCode::SUCCESS
 }
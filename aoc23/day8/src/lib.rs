@@ -0,0 +1,187 @@
+use common::math::lcm_all;
+use common::parse::{split_once, ParseError};
+use common::{Solution, SolveError};
+use std::collections::HashMap;
+use std::fmt::Display;
+
+pub type Navigator = HashMap<String, (String, String)>;
+type Node<'a> = (&'a str, (&'a str, &'a str));
+
+/// Return the number of steps from `AAA` to `ZZZ`, or an error if the network lacks one of them
+/// or `ZZZ` cannot be reached.
+pub fn part1((directions, navigator): &(Vec<u8>, Navigator)) -> Result<u64, SolveError> {
+    if let Some(node) = ["AAA", "ZZZ"].iter().find(|&&n| !navigator.contains_key(n)) {
+        return Err(SolveError::new(format!("no node {}", node)));
+    }
+    navigate_steps(navigator, directions, "AAA", |s: &str| s == "ZZZ")
+        .map(|steps| steps as u64)
+        .ok_or_else(|| SolveError::new("node AAA never reaches ZZZ"))
+}
+
+/// Return the number of steps until all nodes ending in `A` simultaneously reach nodes ending
+/// in `Z`, or an error if there are no such start nodes or they never all reach one.
+pub fn part2((directions, navigator): &(Vec<u8>, Navigator)) -> Result<u64, SolveError> {
+    navigate_steps_simultaneously(navigator, directions, |s: &str| s.ends_with('Z'))
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    type Input = (Vec<u8>, Navigator);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, SolveError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, SolveError> {
+        part2(input)
+    }
+}
+
+pub fn navigate_steps_simultaneously(
+    navigator: &Navigator,
+    directions: &[u8],
+    dest: fn(&str) -> bool,
+) -> Result<u64, SolveError> {
+    let mut start_nodes = navigator
+        .keys()
+        .filter(|k| k.ends_with('A'))
+        .collect::<Vec<&String>>();
+    if start_nodes.is_empty() {
+        return Err(SolveError::new("no node ending in A"));
+    }
+    start_nodes.sort();
+
+    let mut steps_each: Vec<u64> = Vec::with_capacity(start_nodes.len());
+    for node in start_nodes {
+        match navigate_steps(navigator, directions, node, dest) {
+            Some(steps) => steps_each.push(steps as u64),
+            None => {
+                return Err(SolveError::new(format!(
+                    "node {} never reaches a node ending in Z",
+                    node
+                )))
+            }
+        }
+    }
+
+    lcm_all(&steps_each).ok_or_else(|| SolveError::new("the number of steps overflows u64"))
+}
+
+/// Return the number of steps from `start` to the first node satisfying `dest`, or None if the
+/// walk repeats itself before reaching one.
+pub fn navigate_steps(
+    navigator: &Navigator,
+    directions: &[u8],
+    start: &str,
+    dest: fn(&str) -> bool,
+) -> Option<usize> {
+    // The walk is in one of `navigator.len() * directions.len()` states (node, direction index),
+    // so it loops once it takes that many steps without reaching the destination.
+    let limit = navigator.len() * directions.len();
+    let mut curr_loc = start;
+    for (total, direction) in (1..=limit).zip(directions.iter().cycle()) {
+        if direction == &b'L' {
+            curr_loc = &navigator.get(curr_loc).unwrap().0;
+        } else {
+            curr_loc = &navigator.get(curr_loc).unwrap().1;
+        }
+        if dest(curr_loc) {
+            return Some(total);
+        }
+    }
+
+    None
+}
+
+/// Parse the directions and the network of nodes, every node leading to defined nodes.
+pub fn parse(input: &str) -> Result<(Vec<u8>, Navigator), ParseError> {
+    let mut lines = input.lines();
+
+    let directions_str = lines.next().unwrap_or_default().trim();
+    if let Some(i) = directions_str.find(|c| c != 'L' && c != 'R') {
+        let bad = &directions_str[i..i + 1];
+        return Err(ParseError::new(bad, "expected a direction L or R"));
+    }
+    let directions: Vec<u8> = directions_str.to_string().into_bytes();
+
+    let nodes = lines
+        .filter(|line| !line.is_empty())
+        .map(process_line)
+        .collect::<Result<Vec<_>, ParseError>>()?;
+    let mut navigator: Navigator = HashMap::new();
+    for &(loc, _) in &nodes {
+        navigator.insert(loc.to_string(), Default::default());
+    }
+    for (loc, (left, right)) in nodes {
+        for next in [left, right] {
+            if !navigator.contains_key(next) {
+                return Err(ParseError::new(next, format!("no node {}", next)));
+            }
+        }
+        navigator.insert(loc.to_string(), (left.to_string(), right.to_string()));
+    }
+    Ok((directions, navigator))
+}
+
+fn process_line(line: &str) -> Result<Node<'_>, ParseError> {
+    let (loc, dirs) = split_once(line, "=")?;
+    let (left, right) = split_once(dirs, ",")?;
+
+    Ok((
+        loc.trim(),
+        (
+            left.trim().trim_start_matches('('),
+            right.trim().trim_end_matches(')'),
+        ),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn process_line_works() {
+        assert_eq!(
+            process_line("AAA = (BBB, CCC)"),
+            Ok(("AAA", ("BBB", "CCC")))
+        );
+        let err = process_line("AAA (BBB, CCC)").unwrap_err();
+        assert_eq!(err.message(), "expected \"=\"");
+    }
+
+    #[test]
+    fn undefined_nodes() {
+        let input = "LR\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, ZZZ)\n";
+        let err = parse(input).unwrap_err().locate(input);
+        assert_eq!(err.message(), "no node BBB");
+        assert_eq!(
+            err.location().map(|loc| (loc.line, loc.column)),
+            Some((3, 8))
+        );
+
+        let network = parse("LR\n\n11A = (11Z, 11Z)\n11Z = (11Z, 11Z)\n").unwrap();
+        assert_eq!(part2(&network), Ok(1));
+        let err = part1(&network).unwrap_err();
+        assert_eq!(err.message(), "no node AAA");
+    }
+
+    #[test]
+    fn unreachable_destinations() {
+        let network = parse("L\n\nAAA = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)\n").unwrap();
+        let err = part1(&network).unwrap_err();
+        assert_eq!(err.message(), "node AAA never reaches ZZZ");
+        let err = part2(&network).unwrap_err();
+        assert_eq!(err.message(), "node AAA never reaches a node ending in Z");
+
+        let network = parse("LR\n\nBBB = (ZZZ, ZZZ)\nZZZ = (BBB, BBB)\n").unwrap();
+        let err = part2(&network).unwrap_err();
+        assert_eq!(err.message(), "no node ending in A");
+    }
+}
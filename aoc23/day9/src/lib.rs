@@ -0,0 +1,105 @@
+use common::parse::{numbers, ParseError};
+use common::{Solution, SolveError};
+use std::fmt::Display;
+
+/// Parse the report into its histories of values.
+pub fn parse(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    input
+        .lines()
+        .map(|line| match numbers(line)? {
+            nums if nums.is_empty() => Err(ParseError::new(line, "empty history")),
+            nums => Ok(nums),
+        })
+        .collect()
+}
+
+/// Return the sum of the extrapolated next values.
+pub fn part1(histories: &[Vec<i64>]) -> i64 {
+    sum_predicted_values(histories).1
+}
+
+/// Return the sum of the extrapolated previous values.
+pub fn part2(histories: &[Vec<i64>]) -> i64 {
+    sum_predicted_values(histories).0
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, SolveError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, SolveError> {
+        Ok(part2(input))
+    }
+}
+
+pub fn sum_predicted_values(histories: &[Vec<i64>]) -> (i64, i64) {
+    let sum_front = histories.iter().map(|h| extrapolate(h).0).sum();
+    let sum_back = histories.iter().map(|h| extrapolate(h).1).sum();
+    (sum_front, sum_back)
+}
+
+/// Return the values extrapolated before and after the non-empty history `nums`, from its rows of
+/// differences down to one that is all zeros, or empty for histories too short to reach one.
+fn extrapolate(nums: &[i64]) -> (i64, i64) {
+    let mut row = nums.to_vec();
+    let mut firsts = Vec::new();
+    let mut total_back = 0;
+    while row.iter().any(|&n| n != 0) {
+        firsts.push(row[0]);
+        total_back += row[row.len() - 1];
+        row = row.windows(2).map(|w| w[1] - w[0]).collect();
+    }
+
+    let total_front = firsts.iter().rev().fold(0, |front, first| first - front);
+
+    (total_front, total_back)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn process_line(line: &str) -> (i64, i64) {
+        extrapolate(&numbers(line).unwrap())
+    }
+
+    #[test]
+    fn sum_of_extrapolated_values_back() {
+        assert_eq!(process_line("0 3 6 9 12 15").1, 18);
+        assert_eq!(process_line("1 3 6 10 15 21").1, 28);
+        assert_eq!(process_line("10 13 16 21 30 45").1, 68);
+    }
+
+    #[test]
+    fn sum_of_extrapolated_values_front() {
+        assert_eq!(process_line("0 3 6 9 12 15").0, -3);
+        assert_eq!(process_line("1 3 6 10 15 21").0, 0);
+        assert_eq!(process_line("10 13 16 21 30 45").0, 5);
+    }
+
+    #[test]
+    fn short_histories() {
+        assert_eq!(process_line("7"), (7, 7));
+        assert_eq!(process_line("1 2"), (0, 3));
+        assert_eq!(process_line("1 4 9"), (0, 16));
+        assert_eq!(process_line("0 0"), (0, 0));
+    }
+
+    #[test]
+    fn malformed_histories() {
+        let input = "0 3 6\n\n1 x 3\n";
+        assert_eq!(parse(input).unwrap_err().message(), "empty history");
+        let err = parse("0 3 6\n1 x 3\n").unwrap_err();
+        assert_eq!(err.message(), "expected a number, found \"x\"");
+    }
+}
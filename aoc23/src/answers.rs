@@ -0,0 +1,167 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+const HEADER: &str = "\
+# Accepted answers of the puzzle inputs, keyed by day and by the SHA-256 of the input.
+# Checked by `aoc23 verify`; new answers are added with `aoc23 verify --record`.
+";
+
+/// Return the hex encoded SHA-256 of a puzzle input.
+pub fn input_hash(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// Accepted answers of both parts of one puzzle input.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+}
+
+impl Answers {
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, part: u8, answer: String) {
+        match part {
+            1 => self.part1 = Some(answer),
+            2 => self.part2 = Some(answer),
+            _ => {}
+        }
+    }
+}
+
+/// Known answers, keyed by day (`day1`, `day2`, ...) and by the hash of the input.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AnswerStore {
+    days: BTreeMap<String, BTreeMap<String, Answers>>,
+}
+
+impl AnswerStore {
+    /// Load the store from the TOML file at `path`. A missing file is an empty store.
+    pub fn load(path: &Path) -> Result<Self, StoreError> {
+        match fs::read_to_string(path) {
+            Ok(content) => Self::from_toml(&content),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(StoreError::Io(e)),
+        }
+    }
+
+    pub fn from_toml(content: &str) -> Result<Self, StoreError> {
+        toml::from_str(content).map_err(|e| StoreError::Parse(Box::new(e)))
+    }
+
+    pub fn to_toml(&self) -> String {
+        let body = toml::to_string(self).expect("answers serialize to TOML");
+        format!("{}\n{}", HEADER, body)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), StoreError> {
+        fs::write(path, self.to_toml()).map_err(StoreError::Io)
+    }
+
+    /// Return the known answer of `part` of `day` for the input with hash `hash`.
+    pub fn get(&self, day: u8, hash: &str, part: u8) -> Option<&str> {
+        self.days.get(&day_key(day))?.get(hash)?.get(part)
+    }
+
+    /// Record `answer` as the known answer of `part` of `day` for the input with hash `hash`.
+    pub fn set(&mut self, day: u8, hash: &str, part: u8, answer: String) {
+        self.days
+            .entry(day_key(day))
+            .or_default()
+            .entry(hash.to_string())
+            .or_default()
+            .set(part, answer);
+    }
+}
+
+fn day_key(day: u8) -> String {
+    format!("day{}", day)
+}
+
+#[derive(Debug)]
+pub enum StoreError {
+    Io(io::Error),
+    Parse(Box<toml::de::Error>),
+}
+
+impl fmt::Display for StoreError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "cannot access answers file: {}", e),
+            Self::Parse(e) => write!(f, "invalid answers file: {}", e),
+        }
+    }
+}
+
+impl Error for StoreError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STORE: &str = r#"
+[day1.abc]
+part1 = "142"
+part2 = "281"
+
+[day15.def]
+part1 = "1320"
+"#;
+
+    #[test]
+    fn hash_input() {
+        assert_eq!(
+            input_hash(""),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+    }
+
+    #[test]
+    fn get_answers() {
+        let store = AnswerStore::from_toml(STORE).unwrap();
+        assert_eq!(store.get(1, "abc", 1), Some("142"));
+        assert_eq!(store.get(1, "abc", 2), Some("281"));
+        assert_eq!(store.get(15, "def", 2), None);
+        assert_eq!(store.get(1, "def", 1), None);
+        assert_eq!(store.get(2, "abc", 1), None);
+    }
+
+    #[test]
+    fn set_and_roundtrip() {
+        let mut store = AnswerStore::from_toml(STORE).unwrap();
+        store.set(15, "def", 2, "145".to_string());
+        store.set(3, "ghi", 1, "4361".to_string());
+
+        let reloaded = AnswerStore::from_toml(&store.to_toml()).unwrap();
+        assert_eq!(reloaded, store);
+        assert_eq!(reloaded.get(15, "def", 2), Some("145"));
+        assert_eq!(reloaded.get(3, "ghi", 1), Some("4361"));
+    }
+
+    #[test]
+    fn invalid_store() {
+        assert!(matches!(
+            AnswerStore::from_toml("[day1.abc]\npart1 = 142\n"),
+            Err(StoreError::Parse(_))
+        ));
+    }
+}
@@ -0,0 +1,314 @@
+use common::parse::ParseError;
+use common::{SolveError, Solver};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::hint::black_box;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Timed step of a solver.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Parse => write!(f, "parse"),
+            Self::Part1 => write!(f, "part1"),
+            Self::Part2 => write!(f, "part2"),
+        }
+    }
+}
+
+/// Summary of the timings of repeated runs of one stage, in nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub median_ns: u64,
+    pub min_ns: u64,
+    pub stddev_ns: f64,
+}
+
+impl Stats {
+    /// Return the stats of the `samples`, which must not be empty.
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut ns: Vec<u64> = samples.iter().map(|d| d.as_nanos() as u64).collect();
+        ns.sort_unstable();
+
+        let n = ns.len();
+        let median_ns = if n % 2 == 1 {
+            ns[n / 2]
+        } else {
+            (ns[n / 2 - 1] + ns[n / 2]) / 2
+        };
+        let mean = ns.iter().sum::<u64>() as f64 / n as f64;
+        let variance = ns.iter().map(|&x| (x as f64 - mean).powi(2)).sum::<f64>() / n as f64;
+
+        Self {
+            median_ns,
+            min_ns: ns[0],
+            stddev_ns: variance.sqrt(),
+        }
+    }
+}
+
+pub struct Measurement {
+    pub day: u8,
+    pub stage: Stage,
+    pub stats: Stats,
+}
+
+/// Run the parse and both parts of the `solver` on `input` `runs` times, timing each stage
+/// separately. Return an error if the input cannot be parsed or a part fails on it.
+pub fn bench(
+    solver: &dyn Solver,
+    input: &str,
+    runs: usize,
+) -> Result<Vec<Measurement>, BenchError> {
+    let runs = runs.max(1);
+    let mut parse = Vec::with_capacity(runs);
+    let mut part1 = Vec::with_capacity(runs);
+    let mut part2 = Vec::with_capacity(runs);
+
+    for _ in 0..runs {
+        let start = Instant::now();
+        let parsed = black_box(solver.parse(input).map_err(BenchError::Parse)?);
+        parse.push(start.elapsed());
+
+        for (part, samples) in [(1, &mut part1), (2, &mut part2)] {
+            let start = Instant::now();
+            let answer = black_box(solver.solve(part, parsed.as_ref()));
+            samples.push(start.elapsed());
+            if let Some(Err(e)) = answer {
+                return Err(BenchError::Solve(part, e));
+            }
+        }
+    }
+
+    Ok([
+        (Stage::Parse, parse),
+        (Stage::Part1, part1),
+        (Stage::Part2, part2),
+    ]
+    .into_iter()
+    .map(|(stage, samples)| Measurement {
+        day: solver.day(),
+        stage,
+        stats: Stats::from_samples(&samples),
+    })
+    .collect())
+}
+
+#[derive(Debug)]
+pub enum BenchError {
+    Parse(ParseError),
+    Solve(u8, SolveError),
+}
+
+impl fmt::Display for BenchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Parse(e) => write!(f, "{}", e),
+            Self::Solve(part, e) => write!(f, "part {}: {}", part, e),
+        }
+    }
+}
+
+impl Error for BenchError {}
+
+/// Saved stats of a previous benchmark run, keyed by day (`day1`, `day2`, ...) and stage.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Baseline {
+    days: BTreeMap<String, BTreeMap<Stage, Stats>>,
+}
+
+impl Baseline {
+    /// Load the baseline from the JSON file at `path`. A missing file is an empty baseline.
+    pub fn load(path: &Path) -> Result<Self, BaselineError> {
+        match fs::read_to_string(path) {
+            Ok(content) => Self::from_json(&content),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(BaselineError::Io(e)),
+        }
+    }
+
+    pub fn from_json(content: &str) -> Result<Self, BaselineError> {
+        serde_json::from_str(content).map_err(BaselineError::Parse)
+    }
+
+    pub fn to_json(&self) -> String {
+        let mut json = serde_json::to_string_pretty(self).expect("baseline serializes to JSON");
+        json.push('\n');
+        json
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), BaselineError> {
+        fs::write(path, self.to_json()).map_err(BaselineError::Io)
+    }
+
+    pub fn get(&self, day: u8, stage: Stage) -> Option<&Stats> {
+        self.days.get(&format!("day{}", day))?.get(&stage)
+    }
+
+    /// Record the stats of the `measurements`, replacing those of the same day and stage.
+    pub fn update(&mut self, measurements: &[Measurement]) {
+        for m in measurements {
+            self.days
+                .entry(format!("day{}", m.day))
+                .or_default()
+                .insert(m.stage, m.stats);
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum BaselineError {
+    Io(io::Error),
+    Parse(serde_json::Error),
+}
+
+impl fmt::Display for BaselineError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "cannot access baseline file: {}", e),
+            Self::Parse(e) => write!(f, "invalid baseline file: {}", e),
+        }
+    }
+}
+
+impl Error for BaselineError {}
+
+/// Change of the median time of a stage relative to the baseline.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Change {
+    /// The stage has no baseline.
+    New,
+    /// Ratio of the current median to the baseline median, within the threshold.
+    Same(f64),
+    Faster(f64),
+    Slower(f64),
+}
+
+impl Change {
+    /// Compare `current` to `baseline`, where a ratio of the medians further than `threshold`
+    /// (e.g. 0.1 for 10%) from 1 counts as a change.
+    pub fn between(current: &Stats, baseline: Option<&Stats>, threshold: f64) -> Self {
+        let Some(baseline) = baseline else {
+            return Self::New;
+        };
+        let ratio = current.median_ns as f64 / baseline.median_ns.max(1) as f64;
+        if ratio > 1.0 + threshold {
+            Self::Slower(ratio)
+        } else if ratio < 1.0 / (1.0 + threshold) {
+            Self::Faster(ratio)
+        } else {
+            Self::Same(ratio)
+        }
+    }
+
+    pub fn is_regression(&self) -> bool {
+        matches!(self, Self::Slower(_))
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::New => write!(f, "new"),
+            Self::Same(r) => write!(f, "{:+.1}%", (r - 1.0) * 100.0),
+            Self::Faster(r) => write!(f, "{:+.1}% faster", (r - 1.0) * 100.0),
+            Self::Slower(r) => write!(f, "{:+.1}% REGRESSION", (r - 1.0) * 100.0),
+        }
+    }
+}
+
+fn format_ns(ns: f64) -> String {
+    format!("{:.3?}", Duration::from_secs_f64(ns / 1e9))
+}
+
+/// Print a table of the measurements and of their change relative to the baseline.
+pub fn print_table(measurements: &[Measurement], changes: &[Change]) {
+    println!(
+        "{:>3}  {:<5}  {:>12}  {:>12}  {:>12}  Baseline",
+        "Day", "Stage", "Median", "Min", "Stddev"
+    );
+    println!(
+        "{:->3}  {:-<5}  {:->12}  {:->12}  {:->12}  {:-<8}",
+        "", "", "", "", "", ""
+    );
+    for (m, change) in measurements.iter().zip(changes) {
+        println!(
+            "{:>3}  {:<5}  {:>12}  {:>12}  {:>12}  {}",
+            m.day,
+            m.stage,
+            format_ns(m.stats.median_ns as f64),
+            format_ns(m.stats.min_ns as f64),
+            format_ns(m.stats.stddev_ns),
+            change
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    fn stats(median_ns: u64) -> Stats {
+        Stats {
+            median_ns,
+            min_ns: median_ns,
+            stddev_ns: 0.0,
+        }
+    }
+
+    #[test]
+    fn stats_of_samples() {
+        let samples: Vec<Duration> = [40, 10, 30, 20].map(Duration::from_nanos).to_vec();
+        let s = Stats::from_samples(&samples);
+        assert_eq!(s.median_ns, 25);
+        assert_eq!(s.min_ns, 10);
+        assert!((s.stddev_ns - 125f64.sqrt()).abs() < 1e-9);
+
+        let s = Stats::from_samples(&samples[..3]);
+        assert_eq!(s.median_ns, 30);
+    }
+
+    #[test]
+    fn compare_to_baseline() {
+        assert_eq!(Change::between(&stats(100), None, 0.1), Change::New);
+        assert_eq!(
+            Change::between(&stats(105), Some(&stats(100)), 0.1),
+            Change::Same(1.05)
+        );
+        assert!(Change::between(&stats(150), Some(&stats(100)), 0.1).is_regression());
+        assert!(matches!(
+            Change::between(&stats(50), Some(&stats(100)), 0.1),
+            Change::Faster(_)
+        ));
+    }
+
+    #[test]
+    fn bench_and_roundtrip_baseline() {
+        let solver = registry::get(15).unwrap();
+        let measurements = bench(solver, "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9\n", 5).unwrap();
+        let stages: Vec<Stage> = measurements.iter().map(|m| m.stage).collect();
+        assert_eq!(stages, vec![Stage::Parse, Stage::Part1, Stage::Part2]);
+
+        let mut baseline = Baseline::default();
+        baseline.update(&measurements);
+        let reloaded = Baseline::from_json(&baseline.to_json()).unwrap();
+        assert_eq!(reloaded, baseline);
+        assert_eq!(reloaded.get(15, Stage::Part2), Some(&measurements[2].stats));
+        assert_eq!(reloaded.get(1, Stage::Part2), None);
+    }
+}
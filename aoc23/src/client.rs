@@ -0,0 +1,148 @@
+use crate::config::{Config, ConfigError};
+use std::error::Error;
+use std::fmt;
+use std::time::Duration;
+
+const USER_AGENT: &str = concat!("aoc23/", env!("CARGO_PKG_VERSION"));
+
+/// HTTP client of the Advent of Code site, authenticated with a session token.
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+        }
+    }
+
+    pub fn from_config(config: &Config) -> Result<Self, ConfigError> {
+        Ok(Self::new(&config.base_url, config.session()?))
+    }
+
+    /// Download the puzzle input of `day` of `year`.
+    pub fn input(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        let request = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session));
+        read_body(request.call())
+    }
+
+    /// Post `answer` to `part` of `day` of `year` and return the page of the reply.
+    pub fn submit(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<String, ClientError> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let request = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session));
+        read_body(request.send_form(&[("level", &part.to_string()), ("answer", answer)]))
+    }
+}
+
+fn read_body(response: Result<ureq::Response, ureq::Error>) -> Result<String, ClientError> {
+    match response {
+        Ok(response) => response
+            .into_string()
+            .map_err(|e| ClientError::Transport(e.to_string())),
+        Err(ureq::Error::Status(code, response)) => Err(ClientError::Status {
+            code,
+            body: response.into_string().unwrap_or_default(),
+        }),
+        Err(ureq::Error::Transport(e)) => Err(ClientError::Transport(e.to_string())),
+    }
+}
+
+#[derive(Debug)]
+pub enum ClientError {
+    /// The server answered with an error status.
+    Status { code: u16, body: String },
+    /// The server could not be reached or the response could not be read.
+    Transport(String),
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Status { code, body } => {
+                write!(f, "server answered {}", code)?;
+                match body.lines().next() {
+                    Some(line) if !line.trim().is_empty() => write!(f, ": {}", line.trim()),
+                    _ => Ok(()),
+                }
+            }
+            Self::Transport(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for ClientError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stub::Stub;
+
+    #[test]
+    fn download_input() {
+        let stub = Stub::serve(vec![(200, "1abc2\n")]);
+        let client = Client::new(&format!("{}/", stub.url), "s3cr3t");
+        assert_eq!(client.input(2023, 1).unwrap(), "1abc2\n");
+
+        let requests = stub.requests();
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path, "/2023/day/1/input");
+        assert_eq!(requests[0].header("cookie"), Some("session=s3cr3t"));
+        assert!(requests[0]
+            .header("user-agent")
+            .unwrap()
+            .starts_with("aoc23/"));
+    }
+
+    #[test]
+    fn post_answer() {
+        let stub = Stub::serve(vec![(
+            200,
+            "<article><p>That's the right answer!</p></article>",
+        )]);
+        let client = Client::new(&stub.url, "s3cr3t");
+        let reply = client.submit(2023, 9, 2, "1020").unwrap();
+        assert!(reply.contains("right answer"));
+
+        let requests = stub.requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/2023/day/9/answer");
+        assert_eq!(requests[0].header("cookie"), Some("session=s3cr3t"));
+        assert_eq!(requests[0].body, "level=2&answer=1020");
+    }
+
+    #[test]
+    fn error_status() {
+        let stub = Stub::serve(vec![(
+            400,
+            "Puzzle inputs differ by user.  Please log in.\n",
+        )]);
+        let err = Client::new(&stub.url, "expired")
+            .input(2023, 1)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "server answered 400: Puzzle inputs differ by user.  Please log in."
+        );
+    }
+}
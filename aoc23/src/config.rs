@@ -0,0 +1,112 @@
+use serde::Deserialize;
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Environment variable holding the session token, which takes precedence over the config file.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Settings of the Advent of Code client, read from a TOML file such as:
+///
+/// ```toml
+/// session = "53616c746564..."
+/// base_url = "https://adventofcode.com"
+/// cache_dir = "inputs"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: String,
+    /// Directory of the downloaded inputs, relative to the workspace root.
+    pub cache_dir: PathBuf,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            session: None,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            cache_dir: PathBuf::from("inputs"),
+        }
+    }
+}
+
+impl Config {
+    /// Load the config file at `path`, then take the session token from the environment if set.
+    /// A missing file is the default config.
+    pub fn load(path: &Path) -> Result<Self, ConfigError> {
+        let mut config = match fs::read_to_string(path) {
+            Ok(content) => Self::from_toml(&content)?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Self::default(),
+            Err(e) => return Err(ConfigError::Io(e)),
+        };
+        if let Ok(session) = env::var(SESSION_VAR) {
+            config.session = Some(session);
+        }
+        Ok(config)
+    }
+
+    pub fn from_toml(content: &str) -> Result<Self, ConfigError> {
+        toml::from_str(content).map_err(|e| ConfigError::Parse(Box::new(e)))
+    }
+
+    /// Return the session token, with surrounding whitespace removed.
+    pub fn session(&self) -> Result<&str, ConfigError> {
+        match self.session.as_deref().map(str::trim) {
+            Some(session) if !session.is_empty() => Ok(session),
+            _ => Err(ConfigError::NoSession),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(io::Error),
+    Parse(Box<toml::de::Error>),
+    NoSession,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "cannot read config file: {}", e),
+            Self::Parse(e) => write!(f, "invalid config file: {}", e),
+            Self::NoSession => write!(
+                f,
+                "no session token, set {} or `session` in the config file",
+                SESSION_VAR
+            ),
+        }
+    }
+}
+
+impl Error for ConfigError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_config() {
+        let config = Config::from_toml("session = \" abc\\n\"\ncache_dir = \"cache\"\n").unwrap();
+        assert_eq!(config.session().unwrap(), "abc");
+        assert_eq!(config.base_url, DEFAULT_BASE_URL);
+        assert_eq!(config.cache_dir, PathBuf::from("cache"));
+    }
+
+    #[test]
+    fn missing_session() {
+        let config = Config::from_toml("").unwrap();
+        assert!(matches!(config.session(), Err(ConfigError::NoSession)));
+        assert!(matches!(
+            Config::from_toml("sesion = \"abc\"\n"),
+            Err(ConfigError::Parse(_))
+        ));
+    }
+}
@@ -0,0 +1,185 @@
+use crate::html::{articles, tagged};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Example input of one part of a puzzle and its expected answer.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Example {
+    pub part: u8,
+    pub input: String,
+    pub answer: String,
+}
+
+/// Return the directory holding the example fixtures of `day`, relative to `root`.
+pub fn example_dir(root: &Path, day: u8) -> PathBuf {
+    root.join(format!("day{}", day)).join("examples")
+}
+
+/// Return the day of a saved puzzle page, read from its `--- Day N: ... ---` title.
+pub fn page_day(html: &str) -> Option<u8> {
+    let start = html.find("--- Day ")? + "--- Day ".len();
+    let digits: String = html[start..]
+        .chars()
+        .take_while(|c| c.is_ascii_digit())
+        .collect();
+    digits.parse().ok()
+}
+
+/// Extract the examples of both parts from a saved puzzle page.
+///
+/// Each part is an `<article>` of the page. Its expected answer is the last emphasised code
+/// (`<code><em>..</em></code>`) of the article, and its input the last `<pre><code>` block
+/// before that answer, which may be in the article of part 1 when part 2 reuses its example.
+/// Parts without an emphasised answer are left out.
+pub fn extract(html: &str) -> Vec<Example> {
+    let blocks = tagged(html, "<pre><code>", "</code></pre>");
+    let mut examples = Vec::new();
+
+    for (part, (start, end)) in (1..=2).zip(articles(html)) {
+        let article = &html[start..end];
+        let answer = tagged(article, "<code><em>", "</em></code>")
+            .into_iter()
+            .chain(tagged(article, "<em><code>", "</code></em>"))
+            .max_by_key(|(pos, _)| *pos);
+        let Some((pos, answer)) = answer else {
+            continue;
+        };
+        let Some((_, input)) = blocks.iter().rfind(|(p, _)| *p < start + pos) else {
+            continue;
+        };
+        examples.push(Example {
+            part,
+            input: input.clone(),
+            answer,
+        });
+    }
+    examples
+}
+
+/// Write the `examples` of `day` as `partN.input` and `partN.answer` fixtures under `root`.
+/// Existing fixtures are only replaced if `force` is set. Return the paths written.
+pub fn write(root: &Path, day: u8, examples: &[Example], force: bool) -> io::Result<Vec<PathBuf>> {
+    let dir = example_dir(root, day);
+    fs::create_dir_all(&dir)?;
+
+    let files: Vec<(PathBuf, String)> = examples
+        .iter()
+        .flat_map(|e| {
+            [
+                (dir.join(format!("part{}.input", e.part)), e.input.clone()),
+                (
+                    dir.join(format!("part{}.answer", e.part)),
+                    format!("{}\n", e.answer),
+                ),
+            ]
+        })
+        .collect();
+    if let Some((path, _)) = files.iter().find(|(path, _)| path.exists() && !force) {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", path.display()),
+        ));
+    }
+    for (path, content) in &files {
+        fs::write(path, content)?;
+    }
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+/// Load the example fixtures of `day` under `root`. Parts without both files, or with an empty
+/// answer that is yet to be filled in, are left out.
+pub fn load(root: &Path, day: u8) -> io::Result<Vec<Example>> {
+    let dir = example_dir(root, day);
+    let mut examples = Vec::new();
+    for part in 1..=2 {
+        let input = dir.join(format!("part{}.input", part));
+        let answer = dir.join(format!("part{}.answer", part));
+        if !input.exists() || !answer.exists() {
+            continue;
+        }
+        let answer = fs::read_to_string(answer)?.trim().to_string();
+        if answer.is_empty() {
+            continue;
+        }
+        examples.push(Example {
+            part,
+            input: fs::read_to_string(input)?,
+            answer,
+        });
+    }
+    Ok(examples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 15: Lens Library ---</h2>
+<p>For example:</p>
+<pre><code>rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7
+</code></pre>
+<p>The result is <code><em>1320</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>123</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Boxes hold lenses, so <code>a&lt;b</code> with a power of <em>12</em>.</p>
+<p>In the above example, the focusing power is <em><code>145</code></em>.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn extract_page() {
+        assert_eq!(page_day(PAGE), Some(15));
+        let seqs = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7\n";
+        assert_eq!(
+            extract(PAGE),
+            vec![
+                Example {
+                    part: 1,
+                    input: seqs.to_string(),
+                    answer: "1320".to_string()
+                },
+                Example {
+                    part: 2,
+                    input: seqs.to_string(),
+                    answer: "145".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn write_and_load() {
+        let root = tempfile::tempdir().unwrap();
+        let examples = extract(PAGE);
+        write(root.path(), 15, &examples, false).unwrap();
+        assert_eq!(load(root.path(), 15).unwrap(), examples);
+
+        let err = write(root.path(), 15, &examples, false).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+        write(root.path(), 15, &examples, true).unwrap();
+    }
+
+    #[test]
+    fn every_day_solves_its_examples() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        for solver in registry::DAYS {
+            let day = solver.day();
+            for example in load(root, day).unwrap() {
+                let parsed = solver
+                    .parse(&example.input)
+                    .unwrap_or_else(|e| panic!("day {} part {}: {}", day, example.part, e));
+                assert_eq!(
+                    solver.solve(example.part, parsed.as_ref()),
+                    Some(Ok(example.answer.clone())),
+                    "day {} part {}",
+                    day,
+                    example.part
+                );
+            }
+        }
+    }
+}
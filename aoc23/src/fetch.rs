@@ -0,0 +1,155 @@
+use crate::client::{Client, ClientError};
+use crate::config::{Config, ConfigError};
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// Directory of downloaded puzzle inputs, stored as `<dir>/<year>/dayN.txt`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Return the path of the input of `day` of `year`, whether it was downloaded or not.
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("day{}.txt", day))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Return the cached input of `day` of `year`, downloading it first if it is not in the cache.
+/// A cached input is never downloaded again.
+pub fn fetch(cache: &Cache, config: &Config, year: u16, day: u8) -> Result<Fetched, FetchError> {
+    let path = cache.path(year, day);
+    if path.exists() {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let input = Client::from_config(config)?.input(year, day)?;
+
+    // Write to a temporary file first so that an interrupted write is never taken for a cached
+    // input.
+    fs::create_dir_all(path.parent().expect("cache path has a parent"))?;
+    let partial = path.with_extension("part");
+    fs::write(&partial, input)?;
+    fs::rename(&partial, &path)?;
+    Ok(Fetched::Downloaded(path))
+}
+
+#[derive(Debug)]
+pub enum FetchError {
+    Config(ConfigError),
+    Client(ClientError),
+    Io(io::Error),
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Config(e) => write!(f, "{}", e),
+            Self::Client(e) => write!(f, "download failed: {}", e),
+            Self::Io(e) => write!(f, "cannot write the input: {}", e),
+        }
+    }
+}
+
+impl Error for FetchError {}
+
+impl From<ConfigError> for FetchError {
+    fn from(e: ConfigError) -> Self {
+        Self::Config(e)
+    }
+}
+
+impl From<ClientError> for FetchError {
+    fn from(e: ClientError) -> Self {
+        Self::Client(e)
+    }
+}
+
+impl From<io::Error> for FetchError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stub::Stub;
+
+    fn config(base_url: &str, session: Option<&str>) -> Config {
+        Config {
+            session: session.map(str::to_string),
+            base_url: base_url.to_string(),
+            ..Config::default()
+        }
+    }
+
+    #[test]
+    fn fetch_once() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = Cache::new(dir.path());
+        let stub = Stub::serve(vec![(200, "0 3 6 9 12 15\n")]);
+        let config = config(&stub.url, Some("s3cr3t"));
+        let path = dir.path().join("2023").join("day9.txt");
+
+        assert_eq!(
+            fetch(&cache, &config, 2023, 9).unwrap(),
+            Fetched::Downloaded(path.clone())
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "0 3 6 9 12 15\n");
+
+        assert_eq!(
+            fetch(&cache, &config, 2023, 9).unwrap(),
+            Fetched::Cached(path)
+        );
+        assert_eq!(stub.requests().len(), 1);
+    }
+
+    #[test]
+    fn cached_input_needs_no_session() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = Cache::new(dir.path());
+        fs::create_dir_all(dir.path().join("2022")).unwrap();
+        fs::write(cache.path(2022, 1), "1000\n").unwrap();
+
+        let config = config("http://127.0.0.1:9", None);
+        assert!(matches!(
+            fetch(&cache, &config, 2022, 1),
+            Ok(Fetched::Cached(_))
+        ));
+        assert!(matches!(
+            fetch(&cache, &config, 2022, 2),
+            Err(FetchError::Config(ConfigError::NoSession))
+        ));
+    }
+
+    #[test]
+    fn failed_download_is_not_cached() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = Cache::new(dir.path());
+        let stub = Stub::serve(vec![(404, "Not Found")]);
+
+        let err = fetch(&cache, &config(&stub.url, Some("s3cr3t")), 2023, 26).unwrap_err();
+        assert!(matches!(
+            err,
+            FetchError::Client(ClientError::Status { code: 404, .. })
+        ));
+        assert!(!cache.path(2023, 26).exists());
+    }
+}
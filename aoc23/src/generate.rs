@@ -0,0 +1,215 @@
+//! Random puzzle inputs with answers computed by simple references, brute force where it is
+//! feasible, that share no code with the solutions, so that every day can be tested end to end
+//! without the private inputs.
+
+mod day1;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+mod day16;
+mod day2;
+mod day3;
+mod day4;
+mod day5;
+mod day6;
+mod day7;
+mod day8;
+mod day9;
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+/// A random puzzle input and the answers of both parts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Generated {
+    pub input: String,
+    pub part1: String,
+    pub part2: String,
+}
+
+impl Generated {
+    fn new(input: String, part1: impl ToString, part2: impl ToString) -> Self {
+        Self {
+            input,
+            part1: part1.to_string(),
+            part2: part2.to_string(),
+        }
+    }
+}
+
+/// Generator of the random inputs of one day. The meaning of the size depends on the day, _e.g._
+/// the number of lines or the side of a grid, and is documented on each generator.
+pub struct Generator {
+    pub day: u8,
+    /// Size close to that of the real inputs.
+    pub default_size: usize,
+    pub generate: fn(&mut StdRng, usize) -> Generated,
+}
+
+/// Generators of every implemented day, ordered by day number.
+pub static GENERATORS: &[Generator] = &[
+    Generator {
+        day: 1,
+        default_size: 1000,
+        generate: day1::generate,
+    },
+    Generator {
+        day: 2,
+        default_size: 100,
+        generate: day2::generate,
+    },
+    Generator {
+        day: 3,
+        default_size: 140,
+        generate: day3::generate,
+    },
+    Generator {
+        day: 4,
+        default_size: 200,
+        generate: day4::generate,
+    },
+    Generator {
+        day: 5,
+        default_size: 10,
+        generate: day5::generate,
+    },
+    Generator {
+        day: 6,
+        default_size: 4,
+        generate: day6::generate,
+    },
+    Generator {
+        day: 7,
+        default_size: 1000,
+        generate: day7::generate,
+    },
+    Generator {
+        day: 8,
+        default_size: 6,
+        generate: day8::generate,
+    },
+    Generator {
+        day: 9,
+        default_size: 200,
+        generate: day9::generate,
+    },
+    Generator {
+        day: 10,
+        default_size: 70,
+        generate: day10::generate,
+    },
+    Generator {
+        day: 11,
+        default_size: 140,
+        generate: day11::generate,
+    },
+    Generator {
+        day: 12,
+        default_size: 1000,
+        generate: day12::generate,
+    },
+    Generator {
+        day: 13,
+        default_size: 100,
+        generate: day13::generate,
+    },
+    Generator {
+        day: 14,
+        default_size: 100,
+        generate: day14::generate,
+    },
+    Generator {
+        day: 15,
+        default_size: 4000,
+        generate: day15::generate,
+    },
+    Generator {
+        day: 16,
+        default_size: 110,
+        generate: day16::generate,
+    },
+];
+
+/// Return the generator of `day`, or None if the day has none.
+pub fn get(day: u8) -> Option<&'static Generator> {
+    GENERATORS.iter().find(|g| g.day == day)
+}
+
+impl Generator {
+    /// Generate the input of the given `size` from `seed`. The same seed gives the same input.
+    pub fn generate(&self, seed: u64, size: usize) -> Generated {
+        (self.generate)(&mut StdRng::seed_from_u64(seed), size.max(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    /// A day may have no generator yet, as after `new`, but every generator is of a solved day.
+    #[test]
+    fn generators_of_solved_days() {
+        let days: Vec<u8> = GENERATORS.iter().map(|g| g.day).collect();
+        assert!(days.windows(2).all(|w| w[0] < w[1]), "{:?}", days);
+        for day in days {
+            assert!(registry::get(day).is_some(), "day {}", day);
+        }
+    }
+
+    #[test]
+    fn same_seed_same_input() {
+        for g in GENERATORS {
+            assert_eq!(g.generate(7, 10), g.generate(7, 10), "day {}", g.day);
+        }
+    }
+
+    fn check(g: &Generator, seed: u64, size: usize) {
+        let solver = registry::get(g.day).unwrap();
+        let generated = g.generate(seed, size);
+        let parsed = solver
+            .parse(&generated.input)
+            .unwrap_or_else(|e| panic!("day {} seed {} size {}: {}", g.day, seed, size, e));
+        for (part, expected) in [(1, &generated.part1), (2, &generated.part2)] {
+            assert_eq!(
+                solver.solve(part, parsed.as_ref()),
+                Some(Ok(expected.clone())),
+                "day {} part {} seed {} size {}",
+                g.day,
+                part,
+                seed,
+                size
+            );
+        }
+    }
+
+    #[test]
+    fn solutions_match_references() {
+        for g in GENERATORS {
+            for seed in 0..5 {
+                check(g, seed, (g.default_size / 8).max(1));
+            }
+        }
+    }
+
+    #[test]
+    fn small_inputs_match_references() {
+        for g in GENERATORS {
+            for seed in 0..20 {
+                check(g, seed, 1 + seed as usize % 4);
+            }
+        }
+    }
+
+    /// Slow in debug builds, run it with `cargo test --release -- --ignored`.
+    #[test]
+    #[ignore]
+    fn full_size_inputs_match_references() {
+        for g in GENERATORS {
+            check(g, 42, g.default_size);
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use super::Generated;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// Generate `size` lines of letters, digits and spelled digits, each with at least one digit.
+pub fn generate(rng: &mut impl Rng, size: usize) -> Generated {
+    let lines: Vec<String> = (0..size).map(|_| line(rng)).collect();
+    let part1: u64 = lines.iter().map(|l| calibration(l, false)).sum();
+    let part2: u64 = lines.iter().map(|l| calibration(l, true)).sum();
+    Generated::new(lines.join("\n") + "\n", part1, part2)
+}
+
+fn line(rng: &mut impl Rng) -> String {
+    let mut line = String::new();
+    for _ in 0..rng.gen_range(1..8) {
+        match rng.gen_range(0..3) {
+            0 => line.push(char::from(b'0' + rng.gen_range(1..=9))),
+            1 => line.push_str(WORDS.choose(rng).unwrap()),
+            _ => (0..rng.gen_range(1..5)).for_each(|_| line.push(rng.gen_range('a'..='z'))),
+        }
+    }
+    if !line.bytes().any(|b| b.is_ascii_digit()) {
+        let at = rng.gen_range(0..=line.len());
+        line.insert(at, char::from(b'0' + rng.gen_range(1..=9)));
+    }
+    line
+}
+
+/// Return the first and last digit of `line` as a number, from every match of every digit.
+fn calibration(line: &str, spelled: bool) -> u64 {
+    let mut found: Vec<(usize, u64)> = Vec::new();
+    for d in 1..=9u64 {
+        found.extend(line.match_indices(&d.to_string()).map(|(i, _)| (i, d)));
+        if spelled {
+            let word = WORDS[d as usize - 1];
+            found.extend(line.match_indices(word).map(|(i, _)| (i, d)));
+        }
+    }
+    let first = found.iter().min().unwrap().1;
+    let last = found.iter().max().unwrap().1;
+    first * 10 + last
+}
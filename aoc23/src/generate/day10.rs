@@ -0,0 +1,204 @@
+use super::Generated;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::VecDeque;
+
+/// Generate a field of pipes of `2 * size + 1` tiles a side. The loop is the outline of a random
+/// blob of cells without holes. Its start is always on a `7` bend, as the solution assumes.
+pub fn generate(rng: &mut impl Rng, size: usize) -> Generated {
+    let size = size.max(2);
+    let blob = blob(rng, size);
+    let side = 2 * size + 1;
+    let inside = |r: isize, c: isize| {
+        r >= 0
+            && c >= 0
+            && (r as usize) < size
+            && (c as usize) < size
+            && blob[r as usize][c as usize]
+    };
+
+    // Every tile on an even row or column lies on the lines between cells, and is on the loop
+    // when it has the blob on one side only.
+    let mut field = vec![vec![b'.'; side]; side];
+    let mut on_loop = vec![vec![false; side]; side];
+    let edge = |r: usize, c: usize| match (r % 2, c % 2) {
+        (0, 1) => {
+            let (i, j) = (r as isize / 2, c as isize / 2);
+            inside(i - 1, j) != inside(i, j)
+        }
+        (1, 0) => {
+            let (i, j) = (r as isize / 2, c as isize / 2);
+            inside(i, j - 1) != inside(i, j)
+        }
+        _ => false,
+    };
+    for r in 0..side {
+        for c in 0..side {
+            let pipe = match (r % 2, c % 2) {
+                (0, 1) if edge(r, c) => Some(b'-'),
+                (1, 0) if edge(r, c) => Some(b'|'),
+                (0, 0) => {
+                    let north = r > 0 && edge(r - 1, c);
+                    let south = r + 1 < side && edge(r + 1, c);
+                    let west = c > 0 && edge(r, c - 1);
+                    let east = c + 1 < side && edge(r, c + 1);
+                    match (north, south, west, east) {
+                        (true, false, false, true) => Some(b'L'),
+                        (true, false, true, false) => Some(b'J'),
+                        (false, true, true, false) => Some(b'7'),
+                        (false, true, false, true) => Some(b'F'),
+                        (true, true, false, false) => Some(b'|'),
+                        (false, false, true, true) => Some(b'-'),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            };
+            match pipe {
+                Some(p) => {
+                    field[r][c] = p;
+                    on_loop[r][c] = true;
+                }
+                None if rng.gen_bool(0.4) => field[r][c] = *b"|-LJ7F".choose(rng).unwrap(),
+                None => {}
+            }
+        }
+    }
+
+    let length = on_loop.iter().flatten().filter(|&&l| l).count();
+    let enclosed = enclosed(&field, &on_loop);
+
+    let bends: Vec<(usize, usize)> = (0..side)
+        .flat_map(|r| (0..side).map(move |c| (r, c)))
+        .filter(|&(r, c)| field[r][c] == b'7')
+        .filter(|&(r, c)| on_loop[r][c])
+        .collect();
+    let &(r, c) = bends.choose(rng).unwrap();
+    field[r][c] = b'S';
+    // A pipe above the start must not look like it leads into it.
+    if r > 0 && b"|F7".contains(&field[r - 1][c]) {
+        field[r - 1][c] = b'.';
+    }
+
+    let input: String = field
+        .into_iter()
+        .map(|row| String::from_utf8(row).unwrap() + "\n")
+        .collect();
+    Generated::new(input, length / 2, enclosed)
+}
+
+/// Return a `size` by `size` blob of cells grown from a random cell, that has no holes and no two
+/// cells touching only by a corner, so that its outline is a single loop.
+fn blob(rng: &mut impl Rng, size: usize) -> Vec<Vec<bool>> {
+    let mut blob = vec![vec![false; size]; size];
+    let mut cells = vec![(rng.gen_range(0..size), rng.gen_range(0..size))];
+    blob[cells[0].0][cells[0].1] = true;
+    for _ in 0..2 * size * size {
+        let &(r, c) = cells.choose(rng).unwrap();
+        let (r, c) = match rng.gen_range(0..4) {
+            0 => (r + 1, c),
+            1 => (r, c + 1),
+            2 => (r.wrapping_sub(1), c),
+            _ => (r, c.wrapping_sub(1)),
+        };
+        if r >= size || c >= size || blob[r][c] {
+            continue;
+        }
+        blob[r][c] = true;
+        if simple(&blob) {
+            cells.push((r, c));
+        } else {
+            blob[r][c] = false;
+        }
+    }
+    blob
+}
+
+/// Return true if no two cells of `blob` touch only by a corner and every cell outside it is
+/// connected to the border.
+fn simple(blob: &[Vec<bool>]) -> bool {
+    let size = blob.len();
+    // Pad with a ring of outside cells.
+    let at = |r: usize, c: usize| r > 0 && c > 0 && r <= size && c <= size && blob[r - 1][c - 1];
+    for r in 0..=size {
+        for c in 0..=size {
+            let (a, b, x, y) = (at(r, c), at(r, c + 1), at(r + 1, c), at(r + 1, c + 1));
+            if a == y && b == x && a != b {
+                return false;
+            }
+        }
+    }
+    let mut seen = vec![vec![false; size + 2]; size + 2];
+    let mut queue = VecDeque::from([(0usize, 0usize)]);
+    seen[0][0] = true;
+    while let Some((r, c)) = queue.pop_front() {
+        for (nr, nc) in [
+            (r + 1, c),
+            (r, c + 1),
+            (r.wrapping_sub(1), c),
+            (r, c.wrapping_sub(1)),
+        ] {
+            if nr < size + 2 && nc < size + 2 && !seen[nr][nc] && !at(nr, nc) {
+                seen[nr][nc] = true;
+                queue.push_back((nr, nc));
+            }
+        }
+    }
+    (0..size + 2).all(|r| (0..size + 2).all(|c| seen[r][c] || at(r, c)))
+}
+
+/// Return the number of tiles enclosed by the loop, flooding the outside of the field drawn three
+/// times larger so that the flood squeezes between adjacent pipes.
+fn enclosed(field: &[Vec<u8>], on_loop: &[Vec<bool>]) -> usize {
+    // Pad with a ring of open space, which the flood starts from.
+    let side = field.len() * 3 + 2;
+    let mut wall = vec![vec![false; side]; side];
+    for (r, row) in field.iter().enumerate() {
+        for (c, &pipe) in row.iter().enumerate() {
+            if !on_loop[r][c] {
+                continue;
+            }
+            let (north, south, west, east) = match pipe {
+                b'|' => (true, true, false, false),
+                b'-' => (false, false, true, true),
+                b'L' => (true, false, false, true),
+                b'J' => (true, false, true, false),
+                b'7' => (false, true, true, false),
+                _ => (false, true, false, true),
+            };
+            let (cr, cc) = (3 * r + 2, 3 * c + 2);
+            wall[cr][cc] = true;
+            wall[cr - 1][cc] |= north;
+            wall[cr + 1][cc] |= south;
+            wall[cr][cc - 1] |= west;
+            wall[cr][cc + 1] |= east;
+        }
+    }
+
+    let mut seen = vec![vec![false; side]; side];
+    let mut queue = VecDeque::from([(0usize, 0usize)]);
+    seen[0][0] = true;
+    while let Some((r, c)) = queue.pop_front() {
+        for (nr, nc) in [
+            (r + 1, c),
+            (r, c + 1),
+            (r.wrapping_sub(1), c),
+            (r, c.wrapping_sub(1)),
+        ] {
+            if nr < side && nc < side && !seen[nr][nc] && !wall[nr][nc] {
+                seen[nr][nc] = true;
+                queue.push_back((nr, nc));
+            }
+        }
+    }
+
+    let mut count = 0;
+    for (r, row) in field.iter().enumerate() {
+        for c in 0..row.len() {
+            if !on_loop[r][c] && !seen[3 * r + 2][3 * c + 2] {
+                count += 1;
+            }
+        }
+    }
+    count
+}
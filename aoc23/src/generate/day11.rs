@@ -0,0 +1,63 @@
+use super::Generated;
+use rand::Rng;
+
+/// Generate a `size` by `size` image with about one row and column in ten empty, and about one
+/// galaxy in forty tiles elsewhere.
+pub fn generate(rng: &mut impl Rng, size: usize) -> Generated {
+    let empty_rows: Vec<bool> = (0..size).map(|_| rng.gen_bool(0.1)).collect();
+    let empty_cols: Vec<bool> = (0..size).map(|_| rng.gen_bool(0.1)).collect();
+    let image: Vec<Vec<bool>> = (0..size)
+        .map(|r| {
+            (0..size)
+                .map(|c| !empty_rows[r] && !empty_cols[c] && rng.gen_bool(0.025))
+                .collect()
+        })
+        .collect();
+
+    let input: String = image
+        .iter()
+        .map(|row| {
+            let mut line: String = row.iter().map(|&g| if g { '#' } else { '.' }).collect();
+            line.push('\n');
+            line
+        })
+        .collect();
+    Generated::new(input, distances(&image, 2), distances(&image, 1_000_000))
+}
+
+/// Return the sum of the distances between all pairs of galaxies, once every empty row and column
+/// is `factor` times as wide.
+fn distances(image: &[Vec<bool>], factor: i64) -> i64 {
+    let expanded = |empty: Vec<bool>| -> Vec<i64> {
+        let mut at = 0;
+        empty
+            .into_iter()
+            .map(|e| {
+                at += if e { factor } else { 1 };
+                at
+            })
+            .collect()
+    };
+    let rows = expanded(image.iter().map(|row| !row.contains(&true)).collect());
+    let cols = expanded(
+        (0..image[0].len())
+            .map(|c| image.iter().all(|row| !row[c]))
+            .collect(),
+    );
+
+    let mut galaxies = Vec::new();
+    for (r, row) in image.iter().enumerate() {
+        for (c, &g) in row.iter().enumerate() {
+            if g {
+                galaxies.push((rows[r], cols[c]));
+            }
+        }
+    }
+    let mut total = 0;
+    for (i, a) in galaxies.iter().enumerate() {
+        for b in &galaxies[i + 1..] {
+            total += (a.0 - b.0).abs() + (a.1 - b.1).abs();
+        }
+    }
+    total
+}
@@ -0,0 +1,122 @@
+use super::Generated;
+use rand::Rng;
+
+/// Generate `size` condition records of up to 12 springs, each with at least one arrangement.
+///
+/// Part 1 is counted by brute force. Unfolded records have up to 64 springs, too many to try
+/// every arrangement, so part 2 is counted with an automaton instead.
+pub fn generate(rng: &mut impl Rng, size: usize) -> Generated {
+    let mut input = String::new();
+    let mut part1 = 0;
+    let mut part2 = 0;
+    for _ in 0..size {
+        let len = rng.gen_range(1..=12);
+        let mut row: Vec<u8> = (0..len)
+            .map(|_| if rng.gen_bool(0.5) { b'#' } else { b'.' })
+            .collect();
+        row[rng.gen_range(0..len)] = b'#';
+        let groups = groups(&row);
+        for spring in row.iter_mut() {
+            if rng.gen_bool(0.5) {
+                *spring = b'?';
+            }
+        }
+
+        let mut unfolded = row.clone();
+        for _ in 1..5 {
+            unfolded.push(b'?');
+            unfolded.extend(&row);
+        }
+        part1 += brute_force(&row, &groups);
+        part2 += matches(&unfolded, &groups.repeat(5));
+
+        let groups: Vec<String> = groups.iter().map(usize::to_string).collect();
+        input += &format!("{} {}\n", String::from_utf8_lossy(&row), groups.join(","));
+    }
+    Generated::new(input, part1, part2)
+}
+
+/// Return the sizes of the runs of damaged springs in `row`.
+fn groups(row: &[u8]) -> Vec<usize> {
+    row.split(|&s| s != b'#')
+        .map(<[u8]>::len)
+        .filter(|&n| n > 0)
+        .collect()
+}
+
+/// Return the number of arrangements of `row`, trying every way to fill in its unknown springs.
+fn brute_force(row: &[u8], expected: &[usize]) -> u64 {
+    let unknown: Vec<usize> = (0..row.len()).filter(|&i| row[i] == b'?').collect();
+    let mut filled = row.to_vec();
+    let mut count = 0;
+    for bits in 0..1u32 << unknown.len() {
+        for (k, &i) in unknown.iter().enumerate() {
+            filled[i] = if bits >> k & 1 == 1 { b'#' } else { b'.' };
+        }
+        if groups(&filled) == expected {
+            count += 1;
+        }
+    }
+    count
+}
+
+/// Return the number of arrangements of `row` by running it through the automaton of the pattern
+/// `.*#{g1}.+#{g2}...#{gn}.*`, counting the ways to be in each state.
+fn matches(row: &[u8], groups: &[usize]) -> u64 {
+    let mut pattern = vec![b'.'];
+    for &g in groups {
+        pattern.extend(std::iter::repeat_n(b'#', g));
+        pattern.push(b'.');
+    }
+
+    let mut ways = vec![0u64; pattern.len()];
+    ways[0] = 1;
+    for &spring in row {
+        let mut next = vec![0u64; pattern.len()];
+        for (state, &n) in ways.iter().enumerate() {
+            if n == 0 {
+                continue;
+            }
+            for c in [b'.', b'#'] {
+                if spring != b'?' && spring != c {
+                    continue;
+                }
+                if pattern[state] == b'.' && c == b'.' {
+                    next[state] += n;
+                }
+                if pattern.get(state + 1) == Some(&c) {
+                    next[state + 1] += n;
+                }
+            }
+        }
+        ways = next;
+    }
+    ways[pattern.len() - 1] + ways[pattern.len() - 2]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn automaton_agrees_with_brute_force() {
+        let mut rng = StdRng::seed_from_u64(12);
+        for _ in 0..200 {
+            let row: Vec<u8> = (0..rng.gen_range(1..=16))
+                .map(|_| *[b'#', b'.', b'?'].get(rng.gen_range(0..3)).unwrap())
+                .collect();
+            let groups: Vec<usize> = (0..rng.gen_range(1..=4))
+                .map(|_| rng.gen_range(1..=3))
+                .collect();
+            assert_eq!(
+                matches(&row, &groups),
+                brute_force(&row, &groups),
+                "{} {:?}",
+                String::from_utf8_lossy(&row),
+                groups
+            );
+        }
+    }
+}
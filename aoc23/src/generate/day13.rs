@@ -0,0 +1,99 @@
+use super::Generated;
+use rand::Rng;
+
+type Pattern = Vec<Vec<u8>>;
+
+/// Generate `size` patterns, each with exactly one line of perfect reflection and exactly one line
+/// of reflection with a single smudge.
+pub fn generate(rng: &mut impl Rng, size: usize) -> Generated {
+    let mut patterns = Vec::new();
+    let mut part1 = 0;
+    let mut part2 = 0;
+    while patterns.len() < size {
+        let pattern = pattern(rng);
+        let (perfect, smudged) = reflections(&pattern);
+        if let ([perfect], [smudged]) = (&perfect[..], &smudged[..]) {
+            part1 += perfect;
+            part2 += smudged;
+            patterns.push(pattern);
+        }
+    }
+
+    let input: Vec<String> = patterns
+        .into_iter()
+        .map(|p| {
+            p.into_iter()
+                .map(|row| String::from_utf8(row).unwrap() + "\n")
+                .collect()
+        })
+        .collect();
+    Generated::new(input.join("\n"), part1, part2)
+}
+
+/// Return a random pattern mirrored across a row, then across a column, with one tile flipped
+/// in the column mirror but outside the row mirror. Other lines may reflect by chance, so the
+/// caller checks the result.
+fn pattern(rng: &mut impl Rng) -> Pattern {
+    let (nrow, ncol) = (rng.gen_range(5..=15), rng.gen_range(5..=15));
+    let mut p: Pattern = (0..nrow)
+        .map(|_| {
+            (0..ncol)
+                .map(|_| if rng.gen_bool(0.5) { b'#' } else { b'.' })
+                .collect()
+        })
+        .collect();
+
+    let row = rng.gen_range(1..nrow);
+    let reach = row.min(nrow - row);
+    for i in 0..reach {
+        p[row + i] = p[row - 1 - i].clone();
+    }
+    let col = rng.gen_range(1..ncol);
+    let width = col.min(ncol - col);
+    for line in p.iter_mut() {
+        for j in 0..width {
+            line[col + j] = line[col - 1 - j];
+        }
+    }
+    // Flip a tile of a row the row mirror does not reach, if any.
+    let free: Vec<usize> = (0..nrow)
+        .filter(|&r| r + reach < row || r >= row + reach)
+        .collect();
+    if !free.is_empty() {
+        let r = free[rng.gen_range(0..free.len())];
+        let c = col + rng.gen_range(0..width);
+        p[r][c] = if p[r][c] == b'#' { b'.' } else { b'#' };
+    }
+
+    if rng.gen_bool(0.5) {
+        p = (0..ncol)
+            .map(|c| (0..nrow).map(|r| p[r][c]).collect())
+            .collect();
+    }
+    p
+}
+
+/// Return the summaries of the lines of `pattern` that reflect it perfectly, and of those that
+/// reflect it but for one tile.
+fn reflections(pattern: &Pattern) -> (Vec<usize>, Vec<usize>) {
+    let transposed: Pattern = (0..pattern[0].len())
+        .map(|c| pattern.iter().map(|row| row[c]).collect())
+        .collect();
+    let mut perfect = Vec::new();
+    let mut smudged = Vec::new();
+    for (p, weight) in [(pattern, 100), (&transposed, 1)] {
+        for line in 1..p.len() {
+            let mismatches: usize = (0..line)
+                .rev()
+                .zip(line..p.len())
+                .map(|(a, b)| p[a].iter().zip(&p[b]).filter(|(x, y)| x != y).count())
+                .sum();
+            match mismatches {
+                0 => perfect.push(weight * line),
+                1 => smudged.push(weight * line),
+                _ => {}
+            }
+        }
+    }
+    (perfect, smudged)
+}
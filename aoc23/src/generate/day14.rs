@@ -0,0 +1,74 @@
+use super::Generated;
+use rand::Rng;
+
+/// Generate a `size` by `size` platform of round rocks, cube rocks and empty spaces.
+pub fn generate(rng: &mut impl Rng, size: usize) -> Generated {
+    let platform: Vec<Vec<u8>> = (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| match rng.gen_range(0..10) {
+                    0..=1 => b'O',
+                    2 => b'#',
+                    _ => b'.',
+                })
+                .collect()
+        })
+        .collect();
+
+    let mut north = platform.clone();
+    roll(&mut north, (-1, 0));
+    let part1 = load(&north);
+
+    // Keep every platform seen after each cycle, until one repeats.
+    let mut history = vec![platform.clone()];
+    let start = loop {
+        let mut next = history.last().unwrap().clone();
+        for step in [(-1, 0), (0, -1), (1, 0), (0, 1)] {
+            roll(&mut next, step);
+        }
+        if let Some(start) = history.iter().position(|p| *p == next) {
+            break start;
+        }
+        history.push(next);
+    };
+    let period = history.len() - start;
+    let part2 = load(&history[start + (1_000_000_000 - start) % period]);
+
+    let input: String = platform
+        .iter()
+        .map(|row| String::from_utf8_lossy(row).into_owned() + "\n")
+        .collect();
+    Generated::new(input, part1, part2)
+}
+
+/// Move round rocks one tile by `step` while the tile is empty, until none can move.
+fn roll(platform: &mut [Vec<u8>], (dr, dc): (isize, isize)) {
+    let n = platform.len() as isize;
+    let mut moved = true;
+    while moved {
+        moved = false;
+        for r in 0..n {
+            for c in 0..n {
+                let (nr, nc) = (r + dr, c + dc);
+                if !(0..n).contains(&nr) || !(0..n).contains(&nc) {
+                    continue;
+                }
+                let (r, c, nr, nc) = (r as usize, c as usize, nr as usize, nc as usize);
+                if platform[r][c] == b'O' && platform[nr][nc] == b'.' {
+                    platform[r][c] = b'.';
+                    platform[nr][nc] = b'O';
+                    moved = true;
+                }
+            }
+        }
+    }
+}
+
+fn load(platform: &[Vec<u8>]) -> usize {
+    let n = platform.len();
+    platform
+        .iter()
+        .enumerate()
+        .map(|(r, row)| (n - r) * row.iter().filter(|&&b| b == b'O').count())
+        .sum()
+}
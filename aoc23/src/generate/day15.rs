@@ -0,0 +1,51 @@
+use super::Generated;
+use rand::Rng;
+use std::collections::HashMap;
+
+/// Generate an initialization sequence of `size` steps over about `size / 4` labels.
+pub fn generate(rng: &mut impl Rng, size: usize) -> Generated {
+    let labels: Vec<String> = (0..size / 4 + 1)
+        .map(|_| {
+            (0..rng.gen_range(1..=6))
+                .map(|_| rng.gen_range('a'..='z'))
+                .collect()
+        })
+        .collect();
+    let steps: Vec<String> = (0..size)
+        .map(|_| {
+            let label = &labels[rng.gen_range(0..labels.len())];
+            if rng.gen_bool(0.3) {
+                format!("{}-", label)
+            } else {
+                format!("{}={}", label, rng.gen_range(1..=9))
+            }
+        })
+        .collect();
+
+    let part1: u64 = steps.iter().map(|s| hash(s)).sum();
+    // Every lens in a box keeps the time it was first put in, so the slots follow that order.
+    let mut lenses: HashMap<&str, (u64, usize, u64)> = HashMap::new();
+    for (time, step) in steps.iter().enumerate() {
+        match step.split_once('=') {
+            Some((label, focal)) => {
+                let focal = focal.parse().unwrap();
+                lenses.entry(label).or_insert((hash(label), time, 0)).2 = focal;
+            }
+            None => {
+                lenses.remove(step.trim_end_matches('-'));
+            }
+        }
+    }
+    let mut placed: Vec<(u64, usize, u64)> = lenses.into_values().collect();
+    placed.sort_unstable();
+    let mut part2 = 0;
+    for (i, lens) in placed.iter().enumerate() {
+        let slot = placed[..i].iter().filter(|other| other.0 == lens.0).count() as u64 + 1;
+        part2 += (lens.0 + 1) * slot * lens.2;
+    }
+    Generated::new(steps.join(",") + "\n", part1, part2)
+}
+
+fn hash(s: &str) -> u64 {
+    s.bytes().fold(0, |h, b| (h + u64::from(b)) * 17 % 256)
+}
@@ -0,0 +1,69 @@
+use super::Generated;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::{HashSet, VecDeque};
+
+/// Generate a `size` by `size` contraption with about one mirror or splitter in eight tiles.
+pub fn generate(rng: &mut impl Rng, size: usize) -> Generated {
+    let size = size.max(3);
+    let grid: Vec<Vec<u8>> = (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| {
+                    if rng.gen_bool(0.12) {
+                        *b"/\\-|".choose(rng).unwrap()
+                    } else {
+                        b'.'
+                    }
+                })
+                .collect()
+        })
+        .collect();
+
+    let n = size as isize;
+    let mut starts = Vec::new();
+    for i in 0..n {
+        starts.extend([(i, 0, (0, 1)), (i, n - 1, (0, -1))]);
+        starts.extend([(0, i, (1, 0)), (n - 1, i, (-1, 0))]);
+    }
+    let part2 = starts
+        .into_iter()
+        .map(|start| energized(&grid, start))
+        .max()
+        .unwrap();
+
+    let input: String = grid
+        .iter()
+        .map(|row| String::from_utf8(row.clone()).unwrap() + "\n")
+        .collect();
+    Generated::new(input, energized(&grid, (0, 0, (0, 1))), part2)
+}
+
+type Beam = (isize, isize, (isize, isize));
+
+/// Return the number of tiles energized by a beam entering the tile at its row and column with
+/// its row and column steps, following every beam state at most once.
+fn energized(grid: &[Vec<u8>], start: Beam) -> usize {
+    let n = grid.len() as isize;
+    let mut seen = HashSet::from([start]);
+    let mut queue = VecDeque::from([start]);
+    while let Some((r, c, (dr, dc))) = queue.pop_front() {
+        let turns = match grid[r as usize][c as usize] {
+            b'/' => vec![(-dc, -dr)],
+            b'\\' => vec![(dc, dr)],
+            b'-' if dr != 0 => vec![(0, 1), (0, -1)],
+            b'|' if dc != 0 => vec![(1, 0), (-1, 0)],
+            _ => vec![(dr, dc)],
+        };
+        for (dr, dc) in turns {
+            let next = (r + dr, c + dc, (dr, dc));
+            if (0..n).contains(&next.0) && (0..n).contains(&next.1) && seen.insert(next) {
+                queue.push_back(next);
+            }
+        }
+    }
+    seen.into_iter()
+        .map(|(r, c, _)| (r, c))
+        .collect::<HashSet<_>>()
+        .len()
+}
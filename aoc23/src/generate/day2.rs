@@ -0,0 +1,39 @@
+use super::Generated;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const COLORS: [&str; 3] = ["red", "green", "blue"];
+const BAG: [u64; 3] = [12, 13, 14];
+
+/// Generate `size` games of one to six sets of cubes.
+pub fn generate(rng: &mut impl Rng, size: usize) -> Generated {
+    let mut lines = Vec::new();
+    let mut part1 = 0;
+    let mut part2 = 0;
+
+    for id in 1..=size as u64 {
+        let mut most = [0u64; 3];
+        let mut sets = Vec::new();
+        for _ in 0..rng.gen_range(1..=6) {
+            let mut colors = vec![0, 1, 2];
+            colors.shuffle(rng);
+            colors.truncate(rng.gen_range(1..=3));
+            let cubes: Vec<String> = colors
+                .into_iter()
+                .map(|c| {
+                    let n = rng.gen_range(1..=20);
+                    most[c] = most[c].max(n);
+                    format!("{} {}", n, COLORS[c])
+                })
+                .collect();
+            sets.push(cubes.join(", "));
+        }
+        lines.push(format!("Game {}: {}", id, sets.join("; ")));
+
+        if (0..3).all(|c| most[c] <= BAG[c]) {
+            part1 += id;
+        }
+        part2 += most.iter().product::<u64>();
+    }
+    Generated::new(lines.join("\n") + "\n", part1, part2)
+}
@@ -0,0 +1,90 @@
+use super::Generated;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::BTreeSet;
+
+const SYMBOLS: &[u8] = b"*#+$/@=%&-";
+
+/// Generate a `size` by `size` schematic of numbers and symbols.
+pub fn generate(rng: &mut impl Rng, size: usize) -> Generated {
+    let mut grid = vec![vec![b'.'; size]; size];
+    for row in grid.iter_mut() {
+        let mut col = 0;
+        while col < size {
+            let roll = rng.gen_range(0..100);
+            if roll < 20 {
+                let len = rng.gen_range(1..=3).min(size - col);
+                row[col] = b'0' + rng.gen_range(1..=9);
+                for cell in &mut row[col + 1..col + len] {
+                    *cell = b'0' + rng.gen_range(0..=9);
+                }
+                // Leave a gap so that the next number is not glued to this one.
+                col += len + 1;
+            } else {
+                if roll < 30 {
+                    row[col] = if rng.gen_bool(0.4) {
+                        b'*'
+                    } else {
+                        *SYMBOLS.choose(rng).unwrap()
+                    };
+                }
+                col += 1;
+            }
+        }
+    }
+
+    let (part1, part2) = reference(&grid);
+    let input: Vec<String> = grid
+        .into_iter()
+        .map(|row| String::from_utf8(row).unwrap())
+        .collect();
+    Generated::new(input.join("\n") + "\n", part1, part2)
+}
+
+/// Label every digit with the index of its number, then look around every symbol.
+fn reference(grid: &[Vec<u8>]) -> (u64, u64) {
+    let mut values: Vec<u64> = Vec::new();
+    let mut label = vec![vec![None; grid[0].len()]; grid.len()];
+    for (r, row) in grid.iter().enumerate() {
+        for (c, &b) in row.iter().enumerate() {
+            if !b.is_ascii_digit() {
+                continue;
+            }
+            if c > 0 && row[c - 1].is_ascii_digit() {
+                let id: usize = label[r][c - 1].unwrap();
+                values[id] = values[id] * 10 + u64::from(b - b'0');
+                label[r][c] = Some(id);
+            } else {
+                label[r][c] = Some(values.len());
+                values.push(u64::from(b - b'0'));
+            }
+        }
+    }
+
+    let mut parts = BTreeSet::new();
+    let mut ratios = 0;
+    for (r, row) in grid.iter().enumerate() {
+        for (c, &b) in row.iter().enumerate() {
+            if b == b'.' || b.is_ascii_digit() {
+                continue;
+            }
+            let mut around = BTreeSet::new();
+            for dr in -1..=1i64 {
+                for dc in -1..=1i64 {
+                    let (nr, nc) = (r as i64 + dr, c as i64 + dc);
+                    if nr < 0 || nc < 0 || nr >= grid.len() as i64 || nc >= row.len() as i64 {
+                        continue;
+                    }
+                    if let Some(id) = label[nr as usize][nc as usize] {
+                        around.insert(id);
+                    }
+                }
+            }
+            if b == b'*' && around.len() == 2 {
+                ratios += around.iter().map(|&id| values[id]).product::<u64>();
+            }
+            parts.extend(around);
+        }
+    }
+    (parts.iter().map(|&id| values[id]).sum(), ratios)
+}
@@ -0,0 +1,54 @@
+use super::Generated;
+use rand::seq::index::sample;
+use rand::Rng;
+
+/// Generate `size` scratchcards of 10 winning numbers and 25 numbers you have. No card wins
+/// copies of cards past the end of the table.
+pub fn generate(rng: &mut impl Rng, size: usize) -> Generated {
+    let mut lines = Vec::new();
+    let mut matching = Vec::new();
+
+    for i in 0..size {
+        let numbers: Vec<usize> = sample(rng, 99, 35).into_iter().map(|n| n + 1).collect();
+        let (win, others) = numbers.split_at(10);
+        let most = 10.min(size - 1 - i);
+        let m = if rng.gen_bool(0.5) {
+            0
+        } else {
+            rng.gen_range(0..=most)
+        };
+        let own: Vec<usize> = win[..m].iter().chain(&others[..25 - m]).copied().collect();
+        matching.push(m);
+
+        let format = |ns: &[usize]| {
+            ns.iter()
+                .map(|n| format!("{:>2}", n))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        lines.push(format!(
+            "Card {:>3}: {} | {}",
+            i + 1,
+            format(win),
+            format(&shuffled(rng, own))
+        ));
+    }
+
+    let part1: u64 = matching
+        .iter()
+        .map(|&m| if m == 0 { 0 } else { 1 << (m - 1) })
+        .sum();
+    // Each card is worth itself plus the cards its copies win, counted from the last card.
+    let mut worth = vec![0u64; size];
+    for i in (0..size).rev() {
+        worth[i] = 1 + worth[i + 1..=i + matching[i]].iter().sum::<u64>();
+    }
+    let part2: u64 = worth.iter().sum();
+    Generated::new(lines.join("\n") + "\n", part1, part2)
+}
+
+fn shuffled(rng: &mut impl Rng, mut v: Vec<usize>) -> Vec<usize> {
+    use rand::seq::SliceRandom;
+    v.shuffle(rng);
+    v
+}
@@ -0,0 +1,90 @@
+use super::Generated;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const STAGES: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+/// Every id, and every seed range, is below this bound.
+const IDS: u64 = 1000;
+
+/// A mapped range as written in the almanac: destination start, source start and length.
+type Entry = (u64, u64, u64);
+
+/// Generate an almanac of `size` seed ranges. Every map shuffles blocks of `0..IDS`, because the
+/// solution only fills the gaps of a map up to the largest seed.
+pub fn generate(rng: &mut impl Rng, size: usize) -> Generated {
+    let seeds: Vec<(u64, u64)> = (0..size)
+        .map(|_| {
+            let start = rng.gen_range(0..IDS - 1);
+            (start, rng.gen_range(1..=(IDS - start).min(200)))
+        })
+        .collect();
+    let maps: Vec<Vec<Entry>> = STAGES.iter().map(|_| shuffle_blocks(rng)).collect();
+
+    let mut input = "seeds:".to_string();
+    for (start, len) in &seeds {
+        input += &format!(" {} {}", start, len);
+    }
+    input.push('\n');
+    for (name, entries) in STAGES.iter().zip(&maps) {
+        input += &format!("\n{} map:\n", name);
+        for (dst, src, len) in entries {
+            input += &format!("{} {} {}\n", dst, src, len);
+        }
+    }
+
+    let part1 = seeds
+        .iter()
+        .flat_map(|&(a, b)| [a, b])
+        .map(|id| location(&maps, id))
+        .min()
+        .unwrap();
+    let part2 = seeds
+        .iter()
+        .flat_map(|&(start, len)| start..start + len)
+        .map(|id| location(&maps, id))
+        .min()
+        .unwrap();
+    Generated::new(input, part1, part2)
+}
+
+/// Cut `0..IDS` into blocks and lay them out again in random order.
+fn shuffle_blocks(rng: &mut impl Rng) -> Vec<Entry> {
+    let mut cuts: Vec<u64> = (0..rng.gen_range(0..8))
+        .map(|_| rng.gen_range(1..IDS))
+        .collect();
+    cuts.extend([0, IDS]);
+    cuts.sort_unstable();
+    cuts.dedup();
+
+    let mut blocks: Vec<(u64, u64)> = cuts.windows(2).map(|w| (w[0], w[1] - w[0])).collect();
+    blocks.shuffle(rng);
+    let mut dst = 0;
+    let mut entries: Vec<Entry> = blocks
+        .into_iter()
+        .map(|(src, len)| {
+            dst += len;
+            (dst - len, src, len)
+        })
+        .collect();
+    entries.shuffle(rng);
+    entries
+}
+
+/// Return the location of seed `id`, following every map in turn.
+fn location(maps: &[Vec<Entry>], id: u64) -> u64 {
+    maps.iter().fold(id, |id, entries| {
+        entries
+            .iter()
+            .find(|&&(_, src, len)| (src..src + len).contains(&id))
+            .map_or(id, |&(dst, src, _)| dst + id - src)
+    })
+}
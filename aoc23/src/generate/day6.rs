@@ -0,0 +1,45 @@
+use super::Generated;
+use rand::Rng;
+
+/// Generate `size` races, at most three so that the reference can try every hold time of the
+/// single race of part 2.
+pub fn generate(rng: &mut impl Rng, size: usize) -> Generated {
+    let size = size.min(3);
+    loop {
+        let times: Vec<u64> = (0..size).map(|_| rng.gen_range(7..100)).collect();
+        let dists: Vec<u64> = times.iter().map(|&t| rng.gen_range(1..t * t / 4)).collect();
+        let time = concat(&times);
+        let dist = concat(&dists);
+        let big = ways(time, dist);
+        if big == 0 {
+            continue;
+        }
+
+        let part1: u64 = times
+            .iter()
+            .zip(&dists)
+            .map(|(&t, &d)| ways(t, d))
+            .product();
+        let row = |name: &str, ns: &[u64]| {
+            let ns: Vec<String> = ns.iter().map(|n| format!("{:>4}", n)).collect();
+            format!("{:<9}{}\n", name, ns.join(" "))
+        };
+        let input = row("Time:", &times) + &row("Distance:", &dists);
+        return Generated::new(input, part1, big);
+    }
+}
+
+/// Return the number of ways to beat the record `dist` in a race of `time` milliseconds.
+fn ways(time: u64, dist: u64) -> u64 {
+    (0..=time)
+        .filter(|&hold| hold * (time - hold) > dist)
+        .count() as u64
+}
+
+fn concat(ns: &[u64]) -> u64 {
+    ns.iter()
+        .map(u64::to_string)
+        .collect::<String>()
+        .parse()
+        .unwrap()
+}
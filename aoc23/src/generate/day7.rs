@@ -0,0 +1,84 @@
+use super::Generated;
+use rand::Rng;
+use std::collections::HashSet;
+
+const CARDS: &[u8] = b"23456789TJQKA";
+
+/// Generate `size` distinct hands with bids from 1 to 1000.
+pub fn generate(rng: &mut impl Rng, size: usize) -> Generated {
+    let size = size.min(CARDS.len().pow(5));
+    let mut seen = HashSet::new();
+    let mut hands = Vec::new();
+    while hands.len() < size {
+        // Bias towards fewer distinct cards so that the stronger types show up too.
+        let pool = rng.gen_range(2..=CARDS.len());
+        let offset = rng.gen_range(0..=CARDS.len() - pool);
+        let hand: Vec<u8> = (0..5)
+            .map(|_| CARDS[offset + rng.gen_range(0..pool)])
+            .collect();
+        if seen.insert(hand.clone()) {
+            hands.push((hand, rng.gen_range(1..=1000u64)));
+        }
+    }
+
+    let input: String = hands
+        .iter()
+        .map(|(hand, bid)| format!("{} {}\n", String::from_utf8_lossy(hand), bid))
+        .collect();
+    Generated::new(input, winnings(&hands, false), winnings(&hands, true))
+}
+
+/// Return the total winnings, ranking every hand by its type then by its cards in order.
+fn winnings(hands: &[(Vec<u8>, u64)], jokers: bool) -> u64 {
+    let mut keyed: Vec<((u8, Vec<usize>), u64)> = hands
+        .iter()
+        .map(|(hand, bid)| {
+            let strength = hand
+                .iter()
+                .map(|&c| match c {
+                    b'J' if jokers => 0,
+                    _ => CARDS.iter().position(|&x| x == c).unwrap() + 1,
+                })
+                .collect();
+            ((kind(hand, jokers), strength), *bid)
+        })
+        .collect();
+    keyed.sort();
+    keyed
+        .iter()
+        .enumerate()
+        .map(|(rank, (_, bid))| (rank as u64 + 1) * bid)
+        .sum()
+}
+
+/// Return the type of `hand` from 0 (high card) to 6 (five of a kind), trying every card in
+/// place of every joker.
+fn kind(hand: &[u8], jokers: bool) -> u8 {
+    let jokers: Vec<usize> = (0..5).filter(|&i| jokers && hand[i] == b'J').collect();
+    let mut best = 0;
+    let mut cards = hand.to_vec();
+    for choice in 0..CARDS.len().pow(jokers.len() as u32) {
+        let mut rest = choice;
+        for &i in &jokers {
+            cards[i] = CARDS[rest % CARDS.len()];
+            rest /= CARDS.len();
+        }
+        let mut counts: Vec<usize> = CARDS
+            .iter()
+            .map(|c| cards.iter().filter(|&x| x == c).count())
+            .filter(|&n| n > 0)
+            .collect();
+        counts.sort_unstable_by(|a, b| b.cmp(a));
+        let kind = match counts[..] {
+            [5] => 6,
+            [4, ..] => 5,
+            [3, 2] => 4,
+            [3, ..] => 3,
+            [2, 2, ..] => 2,
+            [2, ..] => 1,
+            _ => 0,
+        };
+        best = best.max(kind);
+    }
+    best
+}
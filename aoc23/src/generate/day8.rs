@@ -0,0 +1,102 @@
+use super::Generated;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::{HashMap, HashSet};
+
+const PRIMES: [usize; 5] = [2, 3, 5, 7, 11];
+
+/// Generate a network walked by `size` ghosts, at most five. Ghost `i` starts on a node ending in
+/// `A` and first reaches a node ending in `Z` after `L * p_i` steps, where `L` is the length of
+/// the directions and `p_i` a distinct prime, then loops back along the same path. The first
+/// ghost walks from `AAA` to `ZZZ`.
+pub fn generate(rng: &mut impl Rng, size: usize) -> Generated {
+    let ghosts = size.min(PRIMES.len());
+    let directions: Vec<u8> = (0..rng.gen_range(2..=20))
+        .map(|_| if rng.gen_bool(0.5) { b'L' } else { b'R' })
+        .collect();
+    let len = directions.len();
+    let mut primes = PRIMES.to_vec();
+    primes.shuffle(rng);
+
+    let mut names = HashSet::from(["AAA".to_string(), "ZZZ".to_string()]);
+    let mut paths: Vec<Vec<String>> = Vec::new();
+    for (ghost, &prime) in primes.iter().take(ghosts).enumerate() {
+        let steps = len * prime;
+        let mut path = vec![name(rng, &mut names, b'A')];
+        path.extend((1..steps).map(|_| name(rng, &mut names, b'B')));
+        path.push(name(rng, &mut names, b'Z'));
+        if ghost == 0 {
+            path[0] = "AAA".to_string();
+            path[steps] = "ZZZ".to_string();
+        }
+        paths.push(path);
+    }
+
+    let all: Vec<String> = paths.iter().flatten().cloned().collect();
+    let mut network: Vec<(String, String, String)> = Vec::new();
+    for path in &paths {
+        let steps = path.len() - 1;
+        for (j, node) in path.iter().enumerate() {
+            // The node ending in Z loops back to the first node after the start.
+            let next = if j == steps { &path[1] } else { &path[j + 1] };
+            let other = all.choose(rng).unwrap().clone();
+            let (left, right) = match directions[j % len] {
+                b'L' => (next.clone(), other),
+                _ => (other, next.clone()),
+            };
+            network.push((node.clone(), left, right));
+        }
+    }
+    network.shuffle(rng);
+
+    let mut input = String::from_utf8(directions.clone()).unwrap() + "\n\n";
+    for (node, left, right) in &network {
+        input += &format!("{} = ({}, {})\n", node, left, right);
+    }
+
+    let nodes: HashMap<&str, (&str, &str)> = network
+        .iter()
+        .map(|(n, l, r)| (n.as_str(), (l.as_str(), r.as_str())))
+        .collect();
+    let part1 = walk(&nodes, &directions, vec!["AAA"], |n| n == "ZZZ");
+    let starts = nodes.keys().copied().filter(|n| n.ends_with('A')).collect();
+    let part2 = walk(&nodes, &directions, starts, |n| n.ends_with('Z'));
+    Generated::new(input, part1, part2)
+}
+
+/// Return a new unique node name ending in `last`, which is any letter but A and Z if it is B.
+fn name(rng: &mut impl Rng, names: &mut HashSet<String>, last: u8) -> String {
+    loop {
+        let mut name: Vec<u8> = (0..2).map(|_| rng.gen_range(b'A'..=b'Z')).collect();
+        name.push(if last == b'B' {
+            rng.gen_range(b'B'..=b'Y')
+        } else {
+            last
+        });
+        let name = String::from_utf8(name).unwrap();
+        if names.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+/// Return the number of steps until every walker stands on an end node at once.
+fn walk<'a>(
+    nodes: &HashMap<&'a str, (&'a str, &'a str)>,
+    directions: &[u8],
+    mut at: Vec<&'a str>,
+    end: fn(&str) -> bool,
+) -> u64 {
+    let mut steps = 0;
+    for d in directions.iter().cycle() {
+        for node in at.iter_mut() {
+            let (left, right) = nodes[node];
+            *node = if *d == b'L' { left } else { right };
+        }
+        steps += 1;
+        if at.iter().all(|n| end(n)) {
+            return steps;
+        }
+    }
+    unreachable!()
+}
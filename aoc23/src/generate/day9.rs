@@ -0,0 +1,23 @@
+use super::Generated;
+use rand::Rng;
+
+/// Generate `size` histories, each the values of a random integer polynomial at `0..n`, of
+/// degree lower than `n - 1` so that the differences reach zero.
+pub fn generate(rng: &mut impl Rng, size: usize) -> Generated {
+    let mut input = String::new();
+    let mut part1 = 0;
+    let mut part2 = 0;
+    for _ in 0..size {
+        let n = rng.gen_range(3..=21i64);
+        let degree = rng.gen_range(0..=(n - 2).min(5));
+        let coefs: Vec<i64> = (0..=degree).map(|_| rng.gen_range(-5..=5)).collect();
+        let value = |x: i64| coefs.iter().rev().fold(0, |acc, c| acc * x + c);
+
+        let history: Vec<String> = (0..n).map(|x| value(x).to_string()).collect();
+        input += &history.join(" ");
+        input.push('\n');
+        part1 += value(n);
+        part2 += value(-1);
+    }
+    Generated::new(input, part1, part2)
+}
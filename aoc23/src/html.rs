@@ -0,0 +1,60 @@
+//! Minimal scanning of the Advent of Code pages, which are simple enough to not need a parser.
+
+/// Return the byte ranges of the `<article>` elements of the page.
+pub(crate) fn articles(html: &str) -> Vec<(usize, usize)> {
+    let mut ranges = Vec::new();
+    let mut from = 0;
+    while let Some(open) = html[from..].find("<article") {
+        let start = from + open;
+        let end = html[start..]
+            .find("</article>")
+            .map_or(html.len(), |e| start + e);
+        ranges.push((start, end));
+        from = end;
+    }
+    ranges
+}
+
+/// Return the position and decoded text of every element between `open` and `close`.
+pub(crate) fn tagged(html: &str, open: &str, close: &str) -> Vec<(usize, String)> {
+    let mut found = Vec::new();
+    let mut from = 0;
+    while let Some(i) = html[from..].find(open) {
+        let start = from + i + open.len();
+        let Some(len) = html[start..].find(close) else {
+            break;
+        };
+        found.push((from + i, decode(&html[start..start + len])));
+        from = start + len + close.len();
+    }
+    found
+}
+
+/// Strip the tags from an HTML fragment and decode its entities.
+pub(crate) fn decode(fragment: &str) -> String {
+    let mut text = String::with_capacity(fragment.len());
+    let mut in_tag = false;
+    for c in fragment.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_fragment() {
+        assert_eq!(decode("<em>#</em>.&lt;&amp;&gt;"), "#.<&>");
+    }
+}
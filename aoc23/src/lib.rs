@@ -0,0 +1,21 @@
+//! Runner of the Advent of Code 2023 solutions in this workspace.
+
+pub mod answers;
+pub mod bench;
+pub mod client;
+pub mod config;
+pub mod examples;
+pub mod fetch;
+pub mod generate;
+mod html;
+pub mod registry;
+pub mod runner;
+pub mod scaffold;
+pub mod submit;
+pub mod verify;
+
+#[cfg(test)]
+mod stub;
+
+/// Year of the puzzles solved in this workspace.
+pub const YEAR: u16 = 2023;
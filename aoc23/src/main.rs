@@ -1,3 +1,557 @@
-fn main() {
-    println!("Hello, world!");
+use aoc23::answers::AnswerStore;
+use aoc23::bench::{self, Baseline, BenchError, Change};
+use aoc23::config::Config;
+use aoc23::fetch::{self, Cache, Fetched};
+use aoc23::runner::{Format, Outcome};
+use aoc23::submit::{self, History};
+use aoc23::{examples, generate, registry, runner, scaffold, verify, YEAR};
+use clap::{Parser, Subcommand};
+use common::input::{Mode, Source};
+use common::Solver;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+/// Advent of Code 2023 runner.
+#[derive(Parser)]
+#[command(version, about)]
+struct Cli {
+    /// Config file with the session token, site URL and input cache directory.
+    #[arg(long, global = true, default_value = ".aoc23.toml")]
+    config: PathBuf,
+
+    /// Refuse inputs with CRLF line endings, trailing whitespace, a byte order mark or a missing
+    /// final newline, instead of normalising them.
+    #[arg(long, global = true)]
+    strict: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solver of one day, or of every day with `--all`.
+    Run {
+        /// Day to run.
+        #[arg(short, long, required_unless_present = "all", conflicts_with = "all")]
+        day: Option<u8>,
+
+        /// Part to run. Both parts are run if omitted.
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Input file, or `-` for stdin. Defaults to `dayN/data/input.txt`.
+        #[arg(short, long, conflicts_with = "all")]
+        input: Option<String>,
+
+        /// Run every implemented day on its default input.
+        #[arg(long)]
+        all: bool,
+
+        /// Output format.
+        #[arg(short, long, value_enum, default_value_t)]
+        format: Format,
+    },
+    /// Check the answers of every day on its default input against the known answers.
+    Verify {
+        /// Only verify this day.
+        #[arg(short, long)]
+        day: Option<u8>,
+
+        /// Known answers file.
+        #[arg(long, default_value = "answers.toml")]
+        answers: PathBuf,
+
+        /// Add the answers of inputs with no known answer to the answers file.
+        #[arg(long)]
+        record: bool,
+    },
+    /// Time the parse and both parts of every day, and compare them with a saved baseline.
+    Bench {
+        /// Only benchmark this day.
+        #[arg(short, long)]
+        day: Option<u8>,
+
+        /// Input file, or `-` for stdin, such as a large generated input. Defaults to each day's
+        /// input.
+        #[arg(short, long, requires = "day")]
+        input: Option<String>,
+
+        /// Number of runs of each stage.
+        #[arg(short, long, default_value_t = 10)]
+        runs: usize,
+
+        /// Baseline file of a previous run.
+        #[arg(long, default_value = "bench.json")]
+        baseline: PathBuf,
+
+        /// Save the results of this run to the baseline file.
+        #[arg(long)]
+        save: bool,
+
+        /// Slowdown of the median, in percent, reported as a regression.
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Extract the examples of a saved puzzle page into `dayN/examples` fixtures.
+    Examples {
+        /// Saved HTML page of the puzzle.
+        page: PathBuf,
+
+        /// Day of the puzzle. Read from the page title if omitted.
+        #[arg(short, long)]
+        day: Option<u8>,
+
+        /// Replace existing fixtures.
+        #[arg(long)]
+        force: bool,
+    },
+    /// Download the puzzle input of a day into the input cache, unless it is already there.
+    Fetch {
+        /// Day of the puzzle.
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Year of the puzzle.
+        #[arg(short, long, default_value_t = YEAR)]
+        year: u16,
+
+        /// URL of the Advent of Code site, overriding the config file.
+        #[arg(long)]
+        base_url: Option<String>,
+    },
+    /// Solve a part and submit its answer, unless earlier submissions show it is wrong.
+    Submit {
+        /// Day to submit.
+        #[arg(short, long)]
+        day: u8,
+
+        /// Part to submit.
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Input file, or `-` for stdin. Defaults to the day's input.
+        #[arg(short, long)]
+        input: Option<String>,
+
+        /// URL of the Advent of Code site, overriding the config file.
+        #[arg(long)]
+        base_url: Option<String>,
+
+        /// File recording every submission and its reply.
+        #[arg(long, default_value = "submissions.toml")]
+        history: PathBuf,
+    },
+    /// Create the crate of a new day from the template and register it.
+    New {
+        /// Day to create.
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Write a random input of a day, and print its answers found without the day's solution.
+    Generate {
+        /// Day of the input.
+        #[arg(short, long)]
+        day: u8,
+
+        /// Size of the input, in lines, tiles a side or items depending on the day.
+        #[arg(short, long)]
+        size: Option<usize>,
+
+        /// Seed of the random input. The same seed and size give the same input.
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// File to write the input to. Printed to stdout if omitted, with the answers on stderr.
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let config = match Config::load(&cli.config) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("{}: {}", cli.config.display(), e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let cache = Cache::new(&config.cache_dir);
+    let mode = if cli.strict {
+        Mode::Strict
+    } else {
+        Mode::Normalize
+    };
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            all: _,
+            format,
+        } => run(day, part, input, format, mode, &cache),
+        Command::Verify {
+            day,
+            answers,
+            record,
+        } => verify(day, answers, record, mode, &cache),
+        Command::Bench {
+            day,
+            input,
+            runs,
+            baseline,
+            save,
+            threshold,
+        } => match select_inputs(day, input.as_deref(), &cache) {
+            Ok(targets) => bench(targets, runs, baseline, save, threshold, mode),
+            Err(code) => code,
+        },
+        Command::Examples { page, day, force } => extract_examples(page, day, force),
+        Command::Fetch {
+            day,
+            year,
+            base_url,
+        } => {
+            let mut config = config;
+            if let Some(url) = base_url {
+                config.base_url = url;
+            }
+            fetch(&config, &cache, year, day)
+        }
+        Command::Submit {
+            day,
+            part,
+            input,
+            base_url,
+            history,
+        } => {
+            let mut config = config;
+            if let Some(url) = base_url {
+                config.base_url = url;
+            }
+            submit(&config, &cache, day, part, input, history, mode)
+        }
+        Command::New { day } => new_day(day),
+        Command::Generate {
+            day,
+            size,
+            seed,
+            output,
+        } => generate(day, size, seed, output),
+    }
+}
+
+/// Return the solver of `day`, or every solver if `day` is None.
+fn select(day: Option<u8>) -> Result<Vec<&'static dyn Solver>, ExitCode> {
+    match day {
+        Some(d) => match registry::get(d) {
+            Some(solver) => Ok(vec![solver]),
+            None => {
+                eprintln!("Day {} is not implemented", d);
+                Err(ExitCode::FAILURE)
+            }
+        },
+        None => Ok(registry::DAYS.to_vec()),
+    }
+}
+
+/// Return the solver of `day`, or every solver if `day` is None, each with the source of its
+/// input: `input` if given, otherwise its default input.
+fn select_inputs(
+    day: Option<u8>,
+    input: Option<&str>,
+    cache: &Cache,
+) -> Result<Vec<(&'static dyn Solver, Source)>, ExitCode> {
+    Ok(select(day)?
+        .into_iter()
+        .map(|solver| {
+            let source = match input {
+                Some(arg) => Source::from_arg(arg),
+                None => runner::default_input(solver.day(), cache),
+            };
+            (solver, source)
+        })
+        .collect())
+}
+
+fn run(
+    day: Option<u8>,
+    part: Option<u8>,
+    input: Option<String>,
+    format: Format,
+    mode: Mode,
+    cache: &Cache,
+) -> ExitCode {
+    let targets = match select_inputs(day, input.as_deref(), cache) {
+        Ok(targets) => targets,
+        Err(code) => return code,
+    };
+    let parts = match part {
+        Some(p) => vec![p],
+        None => vec![1, 2],
+    };
+
+    let mut reports = Vec::new();
+    for (solver, source) in targets {
+        reports.extend(runner::run(solver, &parts, &source, mode));
+    }
+    runner::print(&reports, format);
+
+    if reports
+        .iter()
+        .all(|r| matches!(r.outcome, Outcome::Solved(_)))
+    {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn verify(day: Option<u8>, answers: PathBuf, record: bool, mode: Mode, cache: &Cache) -> ExitCode {
+    let solvers = match select(day) {
+        Ok(solvers) => solvers,
+        Err(code) => return code,
+    };
+    let mut store = match AnswerStore::load(&answers) {
+        Ok(store) => store,
+        Err(e) => {
+            eprintln!("{}: {}", answers.display(), e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut checks = Vec::new();
+    for solver in solvers {
+        let source = runner::default_input(solver.day(), cache);
+        checks.extend(verify::verify(solver, &source, &mut store, record, mode));
+    }
+    verify::print_table(&checks);
+
+    if record {
+        if let Err(e) = store.save(&answers) {
+            eprintln!("{}: {}", answers.display(), e);
+            return ExitCode::FAILURE;
+        }
+    }
+
+    if checks
+        .iter()
+        .any(|c| matches!(c.status, verify::Status::Fail { .. }))
+    {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn bench(
+    targets: Vec<(&dyn Solver, Source)>,
+    runs: usize,
+    path: PathBuf,
+    save: bool,
+    threshold: f64,
+    mode: Mode,
+) -> ExitCode {
+    let mut baseline = match Baseline::load(&path) {
+        Ok(baseline) => baseline,
+        Err(e) => {
+            eprintln!("{}: {}", path.display(), e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut measurements = Vec::new();
+    for (solver, source) in targets {
+        let input = match source.load_with(mode) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("Skipping day {}: {}", solver.day(), e);
+                continue;
+            }
+        };
+        match bench::bench(solver, &input, runs) {
+            Ok(day_measurements) => measurements.extend(day_measurements),
+            Err(BenchError::Parse(e)) => {
+                eprintln!("Skipping day {}: {}", solver.day(), e.with_file(&source))
+            }
+            Err(e) => eprintln!("Skipping day {}: {}", solver.day(), e),
+        }
+    }
+    let changes: Vec<Change> = measurements
+        .iter()
+        .map(|m| Change::between(&m.stats, baseline.get(m.day, m.stage), threshold / 100.0))
+        .collect();
+    bench::print_table(&measurements, &changes);
+
+    if save {
+        baseline.update(&measurements);
+        if let Err(e) = baseline.save(&path) {
+            eprintln!("{}: {}", path.display(), e);
+            return ExitCode::FAILURE;
+        }
+    }
+
+    if changes.iter().any(Change::is_regression) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn extract_examples(page: PathBuf, day: Option<u8>, force: bool) -> ExitCode {
+    let html = match fs::read_to_string(&page) {
+        Ok(html) => html,
+        Err(e) => {
+            eprintln!("{}: {}", page.display(), e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let Some(day) = day.or_else(|| examples::page_day(&html)) else {
+        eprintln!("{}: no day in the page title, use --day", page.display());
+        return ExitCode::FAILURE;
+    };
+
+    let found = examples::extract(&html);
+    if found.is_empty() {
+        eprintln!("{}: no example with an answer found", page.display());
+        return ExitCode::FAILURE;
+    }
+    match examples::write(Path::new("."), day, &found, force) {
+        Ok(paths) => {
+            for example in &found {
+                println!("Day {} part {}: {}", day, example.part, example.answer);
+            }
+            for path in paths {
+                println!("Wrote {}", path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn fetch(config: &Config, cache: &Cache, year: u16, day: u8) -> ExitCode {
+    match fetch::fetch(cache, config, year, day) {
+        Ok(Fetched::Cached(path)) => {
+            println!("Already downloaded: {}", path.display());
+            ExitCode::SUCCESS
+        }
+        Ok(Fetched::Downloaded(path)) => {
+            println!("Downloaded {}", path.display());
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("Day {} of {}: {}", day, year, e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn submit(
+    config: &Config,
+    cache: &Cache,
+    day: u8,
+    part: u8,
+    input: Option<String>,
+    path: PathBuf,
+    mode: Mode,
+) -> ExitCode {
+    let Some(solver) = registry::get(day) else {
+        eprintln!("Day {} is not implemented", day);
+        return ExitCode::FAILURE;
+    };
+    let source = match &input {
+        Some(arg) => Source::from_arg(arg),
+        None => runner::default_input(day, cache),
+    };
+    let report = runner::run(solver, &[part], &source, mode).remove(0);
+    let answer = match report.outcome {
+        Outcome::Solved(answer) => answer,
+        Outcome::ParseError(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+        outcome => {
+            eprintln!("{}", outcome);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut history = match History::load(&path) {
+        Ok(history) => history,
+        Err(e) => {
+            eprintln!("{}: {}", path.display(), e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let result = submit::submit(config, &mut history, YEAR, day, part, &answer);
+    if let Err(e) = history.save(&path) {
+        eprintln!("{}: {}", path.display(), e);
+        return ExitCode::FAILURE;
+    }
+
+    match result {
+        Ok(submission) => {
+            println!("Day {} part {}: {}", day, part, answer);
+            println!("{}", submission.message);
+            if submission.verdict == submit::Verdict::Correct {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Err(e) => {
+            eprintln!("Day {} part {}: {}: {}", day, part, answer, e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn new_day(day: u8) -> ExitCode {
+    match scaffold::new_day(Path::new("."), day) {
+        Ok(paths) => {
+            for path in paths {
+                println!("Wrote {}", path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("Day {}: {}", day, e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn generate(day: u8, size: Option<usize>, seed: u64, output: Option<PathBuf>) -> ExitCode {
+    let Some(generator) = generate::get(day) else {
+        eprintln!("Day {} has no generator", day);
+        return ExitCode::FAILURE;
+    };
+    let generated = generator.generate(seed, size.unwrap_or(generator.default_size));
+    let answers = format!(
+        "Day {} part 1: {}\nDay {} part 2: {}",
+        day, generated.part1, day, generated.part2
+    );
+
+    match output {
+        Some(path) => {
+            if let Err(e) = fs::write(&path, &generated.input) {
+                eprintln!("{}: {}", path.display(), e);
+                return ExitCode::FAILURE;
+            }
+            println!("Wrote {}", path.display());
+            println!("{}", answers);
+        }
+        None => {
+            print!("{}", generated.input);
+            eprintln!("{}", answers);
+        }
+    }
+    ExitCode::SUCCESS
 }
@@ -0,0 +1,61 @@
+use common::Solver;
+
+/// Every implemented day, ordered by day number.
+pub static DAYS: &[&dyn Solver] = &[
+    &day1::Day1,
+    &day2::Day2,
+    &day3::Day3,
+    &day4::Day4,
+    &day5::Day5,
+    &day6::Day6,
+    &day7::Day7,
+    &day8::Day8,
+    &day9::Day9,
+    &day10::Day10,
+    &day11::Day11,
+    &day12::Day12,
+    &day13::Day13,
+    &day14::Day14,
+    &day15::Day15,
+    &day16::Day16,
+];
+
+/// Return the solver of `day`, or None if the day is not implemented.
+pub fn get(day: u8) -> Option<&'static dyn Solver> {
+    DAYS.iter().find(|s| s.day() == day).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn days_are_ordered_and_unique() {
+        let days = DAYS.iter().map(|s| s.day()).collect::<Vec<u8>>();
+        assert!(days.windows(2).all(|w| w[0] < w[1]));
+        assert!(days.iter().all(|d| (1..=25).contains(d)));
+    }
+
+    #[test]
+    fn get_day() {
+        assert_eq!(get(5).map(|s| s.day()), Some(5));
+        assert!(get(17).is_none());
+    }
+
+    #[test]
+    fn solve_sample() {
+        let solver = get(15).unwrap();
+        let parsed = solver
+            .parse("rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7")
+            .unwrap();
+        assert_eq!(
+            solver.solve(1, parsed.as_ref()),
+            Some(Ok("1320".to_string()))
+        );
+        assert_eq!(
+            solver.solve(2, parsed.as_ref()),
+            Some(Ok("145".to_string()))
+        );
+        assert_eq!(solver.solve(3, parsed.as_ref()), None);
+    }
+}
@@ -0,0 +1,346 @@
+use crate::answers::input_hash;
+use crate::fetch::Cache;
+use crate::YEAR;
+use clap::ValueEnum;
+use common::input::{Mode, Source};
+use common::parse::ParseError;
+use common::{SolveError, Solver};
+use serde::Serialize;
+use std::fmt;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+/// Return the default input file of `day`, relative to the workspace root: the day's own
+/// `data/input.txt` if present, otherwise the downloaded input in the `cache`.
+pub fn default_input(day: u8, cache: &Cache) -> Source {
+    let own = PathBuf::from(format!("day{}/data/input.txt", day));
+    if own.exists() {
+        Source::File(own)
+    } else {
+        Source::File(cache.path(YEAR, day))
+    }
+}
+
+/// Outcome of running a single day/part.
+pub enum Outcome {
+    Solved(String),
+    InputError(String),
+    ParseError(ParseError),
+    SolveError(SolveError),
+    NotImplemented,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Solved(answer) => write!(f, "{}", answer),
+            Self::InputError(e) => write!(f, "{}", e),
+            Self::ParseError(e) => write!(f, "{}", e.summary()),
+            Self::SolveError(e) => write!(f, "{}", e),
+            Self::NotImplemented => write!(f, "not implemented"),
+        }
+    }
+}
+
+pub struct Report {
+    pub day: u8,
+    pub part: u8,
+    pub outcome: Outcome,
+    /// Time to parse the input, shared by the parts run on it.
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+    /// Hash of the input, if it could be loaded.
+    pub input_hash: Option<String>,
+}
+
+/// Machine readable result of a solved part.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Record<'a> {
+    pub day: u8,
+    pub part: u8,
+    pub answer: &'a str,
+    pub parse_ms: f64,
+    pub solve_ms: f64,
+    pub input_hash: &'a str,
+}
+
+impl Report {
+    /// Return the record of the report, or None if the part was not solved.
+    pub fn record(&self) -> Option<Record<'_>> {
+        match (&self.outcome, &self.input_hash) {
+            (Outcome::Solved(answer), Some(hash)) => Some(Record {
+                day: self.day,
+                part: self.part,
+                answer,
+                parse_ms: self.parse_time.as_secs_f64() * 1e3,
+                solve_ms: self.solve_time.as_secs_f64() * 1e3,
+                input_hash: hash,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Output format of the reports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum Format {
+    /// Table for humans.
+    #[default]
+    Table,
+    /// JSON array of the records of the solved parts.
+    Json,
+    /// One JSON record per line for each solved part.
+    Ndjson,
+}
+
+/// Load the input of the `solver` from `source` once in `mode`, then run and time each of the
+/// `parts`.
+pub fn run(solver: &dyn Solver, parts: &[u8], source: &Source, mode: Mode) -> Vec<Report> {
+    let day = solver.day();
+    let input = match source.load_with(mode) {
+        Ok(input) => input,
+        Err(e) => {
+            return parts
+                .iter()
+                .map(|&part| Report {
+                    day,
+                    part,
+                    outcome: Outcome::InputError(e.to_string()),
+                    parse_time: Duration::ZERO,
+                    solve_time: Duration::ZERO,
+                    input_hash: None,
+                })
+                .collect();
+        }
+    };
+    let mut reports = run_input(solver, parts, &input);
+    for report in &mut reports {
+        if let Outcome::ParseError(e) = &mut report.outcome {
+            *e = e.clone().with_file(source);
+        }
+    }
+    reports
+}
+
+/// Parse the already loaded `input` once, then run and time each of the `parts` of the `solver`.
+pub fn run_input(solver: &dyn Solver, parts: &[u8], input: &str) -> Vec<Report> {
+    let day = solver.day();
+    let input_hash = input_hash(input);
+
+    let start = Instant::now();
+    let parsed = solver.parse(input);
+    let parse_time = start.elapsed();
+    let parsed = match parsed {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            return parts
+                .iter()
+                .map(|&part| Report {
+                    day,
+                    part,
+                    outcome: Outcome::ParseError(e.clone()),
+                    parse_time,
+                    solve_time: Duration::ZERO,
+                    input_hash: Some(input_hash.clone()),
+                })
+                .collect();
+        }
+    };
+
+    parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = solver.solve(part, parsed.as_ref());
+            let solve_time = start.elapsed();
+
+            Report {
+                day,
+                part,
+                outcome: match answer {
+                    Some(Ok(a)) => Outcome::Solved(a),
+                    Some(Err(e)) => Outcome::SolveError(e),
+                    None => Outcome::NotImplemented,
+                },
+                parse_time,
+                solve_time,
+                input_hash: Some(input_hash.clone()),
+            }
+        })
+        .collect()
+}
+
+/// Print a summary table of the reports.
+pub fn print_table(reports: &[Report]) {
+    let width = reports
+        .iter()
+        .map(|r| r.outcome.to_string().len())
+        .max()
+        .unwrap_or(0)
+        .max("Answer".len());
+
+    println!(
+        "{:>3}  {:>4}  {:<width$}  {:>12}",
+        "Day", "Part", "Answer", "Time"
+    );
+    println!("{:->3}  {:->4}  {:-<width$}  {:->12}", "", "", "", "");
+    for r in reports {
+        let elapsed = match r.outcome {
+            Outcome::Solved(_) => format!("{:.3?}", r.parse_time + r.solve_time),
+            _ => "-".to_string(),
+        };
+        println!(
+            "{:>3}  {:>4}  {:<width$}  {:>12}",
+            r.day,
+            r.part,
+            r.outcome.to_string(),
+            elapsed
+        );
+    }
+}
+
+/// Print the parse errors of the reports on stderr, with the offending text underlined, once per
+/// day and error.
+fn print_parse_errors(reports: &[Report]) {
+    let mut printed = Vec::new();
+    for r in reports {
+        if let Outcome::ParseError(e) = &r.outcome {
+            if !printed.contains(&(r.day, e)) {
+                printed.push((r.day, e));
+                eprintln!("{}", e);
+            }
+        }
+    }
+}
+
+/// Print the reports in `format`. Parts that could not be solved are reported on stderr in the
+/// JSON formats, so that stdout only holds records. Parse errors are then detailed on stderr.
+pub fn print(reports: &[Report], format: Format) {
+    if format == Format::Table {
+        print_table(reports);
+        print_parse_errors(reports);
+        return;
+    }
+
+    for r in reports.iter().filter(|r| r.record().is_none()) {
+        eprintln!("Day {} part {}: {}", r.day, r.part, r.outcome);
+    }
+    let records: Vec<Record> = reports.iter().filter_map(Report::record).collect();
+    if format == Format::Json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&records).expect("records serialize to JSON")
+        );
+    } else {
+        for record in records {
+            println!(
+                "{}",
+                serde_json::to_string(&record).expect("records serialize to JSON")
+            );
+        }
+    }
+    print_parse_errors(reports);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+    use std::path::Path;
+
+    const SEQS: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7\n";
+
+    #[test]
+    fn records_of_reports() {
+        let reports = run_input(registry::get(15).unwrap(), &[1, 2, 3], SEQS);
+        let record = reports[1].record().unwrap();
+        assert_eq!((record.day, record.part, record.answer), (15, 2, "145"));
+        assert_eq!(record.input_hash, input_hash(SEQS));
+        assert_eq!(record.parse_ms, reports[0].record().unwrap().parse_ms);
+        assert!(reports[2].record().is_none());
+
+        let json: serde_json::Value = serde_json::to_value(&record).unwrap();
+        let mut keys: Vec<&str> = json
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(|k| k.as_str())
+            .collect();
+        keys.sort_unstable();
+        assert_eq!(
+            keys,
+            [
+                "answer",
+                "day",
+                "input_hash",
+                "parse_ms",
+                "part",
+                "solve_ms"
+            ]
+        );
+    }
+
+    #[test]
+    fn no_record_without_input() {
+        let source = Source::from_arg("./no/such/input.txt");
+        let reports = run(registry::get(15).unwrap(), &[1], &source, Mode::Normalize);
+        assert!(matches!(reports[0].outcome, Outcome::InputError(_)));
+        assert!(reports[0].record().is_none());
+    }
+
+    #[test]
+    fn crlf_input() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("day13/examples/part1.input");
+        let lf = std::fs::read_to_string(path).unwrap();
+        let crlf = Source::Text(lf.replace('\n', "\r\n") + "  \r\n");
+        let solver = registry::get(13).unwrap();
+        let answers = |reports: Vec<Report>| -> Vec<String> {
+            reports.iter().map(|r| r.outcome.to_string()).collect()
+        };
+        assert_eq!(
+            answers(run(solver, &[1, 2], &crlf, Mode::Normalize)),
+            answers(run_input(solver, &[1, 2], &lf))
+        );
+        let strict = run(solver, &[1], &crlf, Mode::Strict);
+        assert_eq!(
+            strict[0].outcome.to_string(),
+            "<text>: CRLF line ending on line 1, trailing whitespace on line 16, \
+             blank lines at end of input on line 16"
+        );
+    }
+
+    #[test]
+    fn parse_error_of_source() {
+        let source = Source::Text("rn=1,cm+\n".to_string());
+        let reports = run(
+            registry::get(15).unwrap(),
+            &[1, 2],
+            &source,
+            Mode::Normalize,
+        );
+        assert_eq!(reports.len(), 2);
+        let Outcome::ParseError(e) = &reports[1].outcome else {
+            panic!("expected a parse error");
+        };
+        assert_eq!(e.file(), Some("<text>"));
+        assert_eq!(e.location().map(|loc| loc.column), Some(8));
+        assert_eq!(
+            reports[0].outcome.to_string(),
+            "<text>:1:8: expected '-' or '=' and a focal length"
+        );
+        assert!(reports[0].record().is_none());
+    }
+
+    #[test]
+    fn solve_error_of_one_part() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("day8/examples/part2.input");
+        let input = std::fs::read_to_string(path).unwrap();
+        let reports = run_input(registry::get(8).unwrap(), &[1, 2], &input);
+        let Outcome::SolveError(e) = &reports[0].outcome else {
+            panic!("expected a solve error");
+        };
+        assert_eq!(e.message(), "no node AAA");
+        assert!(reports[0].record().is_none());
+        assert_eq!(reports[1].outcome.to_string(), "6");
+    }
+}
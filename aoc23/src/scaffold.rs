@@ -0,0 +1,231 @@
+use crate::examples::example_dir;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const CARGO_TEMPLATE: &str = include_str!("../template/Cargo.toml.tpl");
+const LIB_TEMPLATE: &str = include_str!("../template/lib.rs.tpl");
+const MAIN_TEMPLATE: &str = include_str!("../template/main.rs.tpl");
+
+fn render(template: &str, day: u8) -> String {
+    template.replace("{{day}}", &day.to_string())
+}
+
+/// Create the crate of `day` in the workspace at `root` from the template, add it to the
+/// workspace and to the registry, and create empty example fixtures. Return the paths written.
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let dir = root.join(format!("day{}", day));
+    if dir.exists() {
+        return Err(ScaffoldError::Exists(dir));
+    }
+
+    // Edit the existing files before writing anything, so that a failure leaves no half-made day.
+    let manifest_path = root.join("Cargo.toml");
+    let registry_path = root.join("src").join("registry.rs");
+    let manifest = register_in_manifest(&fs::read_to_string(&manifest_path)?, day)?;
+    let registry = register_in_registry(&fs::read_to_string(&registry_path)?, day)?;
+
+    let examples = example_dir(root, day);
+    let files = [
+        (dir.join("Cargo.toml"), render(CARGO_TEMPLATE, day)),
+        (dir.join("src").join("lib.rs"), render(LIB_TEMPLATE, day)),
+        (dir.join("src").join("main.rs"), render(MAIN_TEMPLATE, day)),
+        (examples.join("part1.input"), String::new()),
+        (examples.join("part1.answer"), String::new()),
+        (examples.join("part2.input"), String::new()),
+        (examples.join("part2.answer"), String::new()),
+        (manifest_path, manifest),
+        (registry_path, registry),
+    ];
+    for (path, content) in &files {
+        fs::create_dir_all(path.parent().expect("file has a parent"))?;
+        fs::write(path, content)?;
+    }
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+/// Add `day` to the dependencies and workspace members of the root manifest.
+pub fn register_in_manifest(manifest: &str, day: u8) -> Result<String, ScaffoldError> {
+    let dependency = format!("day{} = {{ path = \"day{}\" }}", day, day);
+    let manifest = insert_sorted(manifest, day, &dependency, |line| {
+        let rest = line.strip_prefix("day")?;
+        let (n, rest) = rest.split_once(' ')?;
+        if !rest.starts_with("= { path = ") {
+            return None;
+        }
+        n.parse().ok()
+    })?;
+
+    let member = format!("    \"day{}\",", day);
+    // A member may be commented out rather than missing.
+    let commented = format!("#{}", member);
+    if manifest.lines().any(|line| line == commented) {
+        return Ok(manifest.replacen(&commented, &member, 1));
+    }
+    insert_sorted(&manifest, day, &member, |line| {
+        line.trim()
+            .strip_prefix("\"day")?
+            .strip_suffix("\",")?
+            .parse()
+            .ok()
+    })
+}
+
+/// Add the solver of `day` to the list of days of the registry.
+pub fn register_in_registry(registry: &str, day: u8) -> Result<String, ScaffoldError> {
+    let entry = format!("    &day{}::Day{},", day, day);
+    insert_sorted(registry, day, &entry, |line| {
+        let rest = line.trim().strip_prefix("&day")?;
+        let (n, _) = rest.split_once("::")?;
+        n.parse().ok()
+    })
+}
+
+/// Insert `line` among the lines for which `key` returns a day, keeping them ordered by day.
+fn insert_sorted(
+    text: &str,
+    day: u8,
+    line: &str,
+    key: impl Fn(&str) -> Option<u8>,
+) -> Result<String, ScaffoldError> {
+    let lines: Vec<&str> = text.lines().collect();
+    let keyed: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, l)| key(l).map(|k| (i, k)))
+        .collect();
+    if keyed.iter().any(|&(_, k)| k == day) {
+        return Err(ScaffoldError::Registered(line.trim().to_string()));
+    }
+    let at = match (keyed.iter().rev().find(|&&(_, k)| k < day), keyed.first()) {
+        (Some(&(i, _)), _) => i + 1,
+        (None, Some(&(i, _))) => i,
+        (None, None) => return Err(ScaffoldError::NoList(line.trim().to_string())),
+    };
+
+    let mut out: Vec<&str> = lines;
+    out.insert(at, line);
+    let mut text = out.join("\n");
+    text.push('\n');
+    Ok(text)
+}
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    /// The directory of the day already exists.
+    Exists(PathBuf),
+    /// This entry is already present.
+    Registered(String),
+    /// No list of days was found to add this entry to.
+    NoList(String),
+    Io(io::Error),
+}
+
+impl fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Exists(dir) => write!(f, "{} already exists", dir.display()),
+            Self::Registered(entry) => write!(f, "`{}` is already registered", entry),
+            Self::NoList(entry) => write!(f, "found no list of days to add `{}` to", entry),
+            Self::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for ScaffoldError {}
+
+impl From<io::Error> for ScaffoldError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = r#"[dependencies]
+common = { path = "common" }
+day1 = { path = "day1" }
+day11 = { path = "day11" }
+day13 = { path = "day13" }
+
+[workspace]
+members = [
+    "common",
+    "day1",
+    "day11",
+#    "day12",
+    "day13",
+]
+"#;
+
+    const REGISTRY: &str = "pub static DAYS: &[&dyn Solver] = &[
+    &day1::Day1,
+    &day11::Day11,
+    &day13::Day13,
+];
+";
+
+    #[test]
+    fn register_days() {
+        let manifest = register_in_manifest(MANIFEST, 12).unwrap();
+        assert!(manifest.contains(
+            "day11 = { path = \"day11\" }\nday12 = { path = \"day12\" }\nday13 = { path = \"day13\" }\n"
+        ));
+        assert!(manifest.contains("    \"day11\",\n    \"day12\",\n    \"day13\",\n"));
+
+        let manifest = register_in_manifest(MANIFEST, 17).unwrap();
+        assert!(manifest.contains("day13 = { path = \"day13\" }\nday17 = { path = \"day17\" }\n\n"));
+        assert!(manifest.contains("    \"day13\",\n    \"day17\",\n]"));
+
+        let registry = register_in_registry(REGISTRY, 2).unwrap();
+        assert!(registry.contains("&day1::Day1,\n    &day2::Day2,\n    &day11::Day11,"));
+
+        assert!(matches!(
+            register_in_registry(REGISTRY, 11),
+            Err(ScaffoldError::Registered(_))
+        ));
+    }
+
+    #[test]
+    fn register_in_workspace_files() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let manifest = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+        let registry = fs::read_to_string(root.join("src/registry.rs")).unwrap();
+        assert!(register_in_manifest(&manifest, 25)
+            .unwrap()
+            .contains("    \"day25\",\n]"));
+        assert!(register_in_registry(&registry, 25)
+            .unwrap()
+            .contains("    &day25::Day25,\n];"));
+    }
+
+    #[test]
+    fn create_day() {
+        let root = tempfile::tempdir().unwrap();
+        fs::write(root.path().join("Cargo.toml"), MANIFEST).unwrap();
+        fs::create_dir(root.path().join("src")).unwrap();
+        fs::write(root.path().join("src/registry.rs"), REGISTRY).unwrap();
+
+        let written = new_day(root.path(), 17).unwrap();
+        assert_eq!(written.len(), 9);
+        let lib = fs::read_to_string(root.path().join("day17/src/lib.rs")).unwrap();
+        assert!(lib.contains("pub struct Day17;"));
+        assert!(lib.contains("const DAY: u8 = 17;"));
+        assert_eq!(
+            fs::read_to_string(root.path().join("day17/examples/part2.answer")).unwrap(),
+            ""
+        );
+        assert!(fs::read_to_string(root.path().join("src/registry.rs"))
+            .unwrap()
+            .contains("&day17::Day17,"));
+
+        assert!(matches!(
+            new_day(root.path(), 17),
+            Err(ScaffoldError::Exists(_))
+        ));
+    }
+}
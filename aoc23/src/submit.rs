@@ -0,0 +1,434 @@
+use crate::client::{Client, ClientError};
+use crate::config::{Config, ConfigError};
+use crate::html::{articles, decode};
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const HEADER: &str = "\
+# Answers submitted by `aoc23 submit` and the replies of the site.
+";
+
+/// Meaning of the reply to a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint of the direction.
+    Wrong,
+    /// Answered too soon after a previous wrong answer; the answer was not checked.
+    TooSoon,
+    /// The part is locked or already solved; the answer was not checked.
+    WrongLevel,
+    Unknown,
+}
+
+impl Verdict {
+    /// Return the verdict of the reply `message`.
+    pub fn from_reply(message: &str) -> Self {
+        if message.contains("That's the right answer") {
+            Self::Correct
+        } else if message.contains("too high") {
+            Self::TooHigh
+        } else if message.contains("too low") {
+            Self::TooLow
+        } else if message.contains("That's not the right answer") {
+            Self::Wrong
+        } else if message.contains("You gave an answer too recently") {
+            Self::TooSoon
+        } else if message.contains("You don't seem to be solving the right level") {
+            Self::WrongLevel
+        } else {
+            Self::Unknown
+        }
+    }
+
+    /// Return whether the site checked the answer, so that it must not be submitted again.
+    fn is_checked(&self) -> bool {
+        matches!(
+            self,
+            Self::Correct | Self::TooHigh | Self::TooLow | Self::Wrong
+        )
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Correct => write!(f, "correct"),
+            Self::TooHigh => write!(f, "too high"),
+            Self::TooLow => write!(f, "too low"),
+            Self::Wrong => write!(f, "wrong"),
+            Self::TooSoon => write!(f, "too soon"),
+            Self::WrongLevel => write!(f, "wrong level"),
+            Self::Unknown => write!(f, "unknown reply"),
+        }
+    }
+}
+
+/// Return the text of the reply page to a submission, with whitespace collapsed.
+pub fn reply_message(page: &str) -> String {
+    let text = match articles(page).first() {
+        Some(&(start, end)) => decode(&page[start..end]),
+        None => decode(page),
+    };
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    pub message: String,
+    /// Seconds since the Unix epoch.
+    pub time: u64,
+}
+
+/// Range an answer must be in, learnt from "too low" and "too high" replies.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Bounds {
+    /// Greatest answer that was too low.
+    pub above: Option<i128>,
+    /// Least answer that was too high.
+    pub below: Option<i128>,
+}
+
+/// Every answer submitted so far.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct History {
+    #[serde(default, rename = "submission")]
+    submissions: Vec<Submission>,
+}
+
+impl History {
+    /// Load the history from the TOML file at `path`. A missing file is an empty history.
+    pub fn load(path: &Path) -> Result<Self, HistoryError> {
+        match fs::read_to_string(path) {
+            Ok(content) => Self::from_toml(&content),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(HistoryError::Io(e)),
+        }
+    }
+
+    pub fn from_toml(content: &str) -> Result<Self, HistoryError> {
+        toml::from_str(content).map_err(|e| HistoryError::Parse(Box::new(e)))
+    }
+
+    pub fn to_toml(&self) -> String {
+        let body = toml::to_string(self).expect("history serializes to TOML");
+        format!("{}\n{}", HEADER, body)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), HistoryError> {
+        fs::write(path, self.to_toml()).map_err(HistoryError::Io)
+    }
+
+    pub fn submissions(&self) -> &[Submission] {
+        &self.submissions
+    }
+
+    pub fn push(&mut self, submission: Submission) {
+        self.submissions.push(submission);
+    }
+
+    fn of(&self, year: u16, day: u8, part: u8) -> impl Iterator<Item = &Submission> {
+        self.submissions
+            .iter()
+            .filter(move |s| s.year == year && s.day == day && s.part == part)
+    }
+
+    /// Return the bounds of the answer of `part` of `day` of `year`.
+    pub fn bounds(&self, year: u16, day: u8, part: u8) -> Bounds {
+        let mut bounds = Bounds::default();
+        for s in self.of(year, day, part) {
+            let Ok(n) = s.answer.trim().parse::<i128>() else {
+                continue;
+            };
+            match s.verdict {
+                Verdict::TooLow => bounds.above = bounds.above.max(Some(n)),
+                Verdict::TooHigh => bounds.below = Some(bounds.below.map_or(n, |b| b.min(n))),
+                _ => {}
+            }
+        }
+        bounds
+    }
+
+    /// Check whether `answer` to `part` of `day` of `year` is worth submitting, given the
+    /// previous submissions.
+    pub fn check(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<(), Refusal> {
+        if let Some(s) = self
+            .of(year, day, part)
+            .find(|s| s.verdict == Verdict::Correct)
+        {
+            return Err(Refusal::Solved(s.answer.clone()));
+        }
+        if let Some(s) = self
+            .of(year, day, part)
+            .find(|s| s.answer == answer && s.verdict.is_checked())
+        {
+            return Err(Refusal::Repeated(s.verdict));
+        }
+        if let Ok(n) = answer.trim().parse::<i128>() {
+            let bounds = self.bounds(year, day, part);
+            match bounds {
+                Bounds {
+                    above: Some(low), ..
+                } if n <= low => return Err(Refusal::TooLow(low)),
+                Bounds {
+                    below: Some(high), ..
+                } if n >= high => return Err(Refusal::TooHigh(high)),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Reason for not submitting an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    /// The part was already solved with this answer.
+    Solved(String),
+    /// The same answer was already checked with this verdict.
+    Repeated(Verdict),
+    /// The answer is not above this answer, which was too low.
+    TooLow(i128),
+    /// The answer is not below this answer, which was too high.
+    TooHigh(i128),
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Solved(answer) => write!(f, "already solved with {}", answer),
+            Self::Repeated(verdict) => write!(f, "already submitted, it was {}", verdict),
+            Self::TooLow(low) => write!(f, "{} was already too low", low),
+            Self::TooHigh(high) => write!(f, "{} was already too high", high),
+        }
+    }
+}
+
+/// Submit `answer` to `part` of `day` of `year` unless the `history` shows it is wrong, and
+/// record the reply in the `history`.
+pub fn submit(
+    config: &Config,
+    history: &mut History,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<Submission, SubmitError> {
+    history
+        .check(year, day, part, answer)
+        .map_err(SubmitError::Refused)?;
+
+    let page = Client::from_config(config)?.submit(year, day, part, answer)?;
+    let message = reply_message(&page);
+    let submission = Submission {
+        year,
+        day,
+        part,
+        answer: answer.to_string(),
+        verdict: Verdict::from_reply(&message),
+        message,
+        time: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs()),
+    };
+    history.push(submission.clone());
+    Ok(submission)
+}
+
+#[derive(Debug)]
+pub enum HistoryError {
+    Io(io::Error),
+    Parse(Box<toml::de::Error>),
+}
+
+impl fmt::Display for HistoryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "cannot access history file: {}", e),
+            Self::Parse(e) => write!(f, "invalid history file: {}", e),
+        }
+    }
+}
+
+impl Error for HistoryError {}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    Refused(Refusal),
+    Config(ConfigError),
+    Client(ClientError),
+}
+
+impl fmt::Display for SubmitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Refused(r) => write!(f, "not submitted: {}", r),
+            Self::Config(e) => write!(f, "{}", e),
+            Self::Client(e) => write!(f, "submission failed: {}", e),
+        }
+    }
+}
+
+impl Error for SubmitError {}
+
+impl From<ConfigError> for SubmitError {
+    fn from(e: ConfigError) -> Self {
+        Self::Config(e)
+    }
+}
+
+impl From<ClientError> for SubmitError {
+    fn from(e: ClientError) -> Self {
+        Self::Client(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stub::Stub;
+
+    const TOO_HIGH: &str = "<main><article><p>That's not the right answer; your answer is too \
+        high.  If you're stuck, make sure you're using the full input data. \
+        [<a href=\"/2023/day/9\">Return to Day 9</a>]</p></article></main>";
+    const TOO_LOW: &str = "<article><p>That's not the right answer; your answer is too low.\
+        </p></article>";
+    const TOO_SOON: &str = "<article><p>You gave an answer too recently; you have to wait \
+        after submitting an answer before trying again.  You have 37s left to wait.</p></article>";
+    const CORRECT: &str = "<article><p>That's the right answer!  You are <em>one gold star</em> \
+        closer to restoring snow operations.</p></article>";
+
+    fn config(base_url: &str) -> Config {
+        Config {
+            session: Some("s3cr3t".to_string()),
+            base_url: base_url.to_string(),
+            ..Config::default()
+        }
+    }
+
+    #[test]
+    fn parse_replies() {
+        assert_eq!(
+            reply_message(TOO_HIGH),
+            "That's not the right answer; your answer is too high. If you're stuck, make sure \
+             you're using the full input data. [Return to Day 9]"
+        );
+        let verdicts: Vec<Verdict> = [TOO_HIGH, TOO_LOW, TOO_SOON, CORRECT, "<p>Oops</p>"]
+            .iter()
+            .map(|page| Verdict::from_reply(&reply_message(page)))
+            .collect();
+        assert_eq!(
+            verdicts,
+            vec![
+                Verdict::TooHigh,
+                Verdict::TooLow,
+                Verdict::TooSoon,
+                Verdict::Correct,
+                Verdict::Unknown
+            ]
+        );
+    }
+
+    #[test]
+    fn bounds_refuse_answers() {
+        let stub = Stub::serve(vec![(200, TOO_HIGH), (200, TOO_LOW), (200, CORRECT)]);
+        let config = config(&stub.url);
+        let mut history = History::default();
+
+        let s = submit(&config, &mut history, 2023, 9, 1, "2000").unwrap();
+        assert_eq!(s.verdict, Verdict::TooHigh);
+        let s = submit(&config, &mut history, 2023, 9, 1, "1000").unwrap();
+        assert_eq!(s.verdict, Verdict::TooLow);
+        assert_eq!(
+            history.bounds(2023, 9, 1),
+            Bounds {
+                above: Some(1000),
+                below: Some(2000)
+            }
+        );
+
+        for (answer, refusal) in [
+            ("2500", Refusal::TooHigh(2000)),
+            ("2000", Refusal::Repeated(Verdict::TooHigh)),
+            ("999", Refusal::TooLow(1000)),
+            ("1000", Refusal::Repeated(Verdict::TooLow)),
+        ] {
+            let err = submit(&config, &mut history, 2023, 9, 1, answer).unwrap_err();
+            assert!(matches!(err, SubmitError::Refused(r) if r == refusal));
+        }
+        // Other parts and days have their own bounds.
+        assert_eq!(history.check(2023, 9, 2, "2500"), Ok(()));
+        assert_eq!(history.check(2023, 10, 1, "2500"), Ok(()));
+
+        let s = submit(&config, &mut history, 2023, 9, 1, "1500").unwrap();
+        assert_eq!(s.verdict, Verdict::Correct);
+        assert_eq!(
+            history.check(2023, 9, 1, "1501"),
+            Err(Refusal::Solved("1500".to_string()))
+        );
+
+        assert_eq!(stub.requests().len(), 3);
+        assert_eq!(history.submissions().len(), 3);
+    }
+
+    #[test]
+    fn unchecked_answers_can_be_resubmitted() {
+        let stub = Stub::serve(vec![(200, TOO_SOON), (200, TOO_HIGH)]);
+        let config = config(&stub.url);
+        let mut history = History::default();
+
+        let s = submit(&config, &mut history, 2023, 1, 2, "54980").unwrap();
+        assert_eq!(s.verdict, Verdict::TooSoon);
+        assert_eq!(history.bounds(2023, 1, 2), Bounds::default());
+
+        submit(&config, &mut history, 2023, 1, 2, "54980").unwrap();
+        assert_eq!(
+            history.check(2023, 1, 2, "54980"),
+            Err(Refusal::Repeated(Verdict::TooHigh))
+        );
+    }
+
+    #[test]
+    fn non_numeric_answers_have_no_bounds() {
+        let mut history = History::default();
+        history.push(Submission {
+            year: 2022,
+            day: 10,
+            part: 2,
+            answer: "EHZFZHCZ".to_string(),
+            verdict: Verdict::Wrong,
+            message: String::new(),
+            time: 0,
+        });
+        assert_eq!(
+            history.check(2022, 10, 2, "EHZFZHCZ"),
+            Err(Refusal::Repeated(Verdict::Wrong))
+        );
+        assert_eq!(history.check(2022, 10, 2, "EHPZPJGL"), Ok(()));
+    }
+
+    #[test]
+    fn history_roundtrip() {
+        let stub = Stub::serve(vec![(200, TOO_LOW)]);
+        let mut history = History::default();
+        submit(&config(&stub.url), &mut history, 2023, 5, 1, "35").unwrap();
+
+        let reloaded = History::from_toml(&history.to_toml()).unwrap();
+        assert_eq!(reloaded, history);
+        assert_eq!(reloaded.submissions()[0].verdict, Verdict::TooLow);
+        assert_eq!(History::from_toml("").unwrap(), History::default());
+    }
+}
@@ -0,0 +1,200 @@
+use crate::answers::{input_hash, AnswerStore};
+use crate::runner::{self, Outcome};
+use common::input::{Mode, Source};
+use common::Solver;
+use std::fmt;
+
+/// Result of checking one day/part against the known answers.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail {
+        expected: String,
+    },
+    /// No answer is known for this input; the computed answer was recorded if asked to.
+    Missing {
+        recorded: bool,
+    },
+    /// The input could not be loaded or solved.
+    Error(String),
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Pass => write!(f, "pass"),
+            Self::Fail { expected } => write!(f, "FAIL (expected {})", expected),
+            Self::Missing { recorded: false } => write!(f, "missing"),
+            Self::Missing { recorded: true } => write!(f, "recorded"),
+            Self::Error(e) => write!(f, "error: {}", e),
+        }
+    }
+}
+
+pub struct Check {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub status: Status,
+}
+
+/// Run both parts of the `solver` on the input from `source`, loaded in `mode`, and compare the
+/// answers with the `store`. If `record` is set, answers missing from the store are added to it.
+pub fn verify(
+    solver: &dyn Solver,
+    source: &Source,
+    store: &mut AnswerStore,
+    record: bool,
+    mode: Mode,
+) -> Vec<Check> {
+    let day = solver.day();
+    let input = match source.load_with(mode) {
+        Ok(input) => input,
+        Err(e) => {
+            return [1, 2]
+                .into_iter()
+                .map(|part| Check {
+                    day,
+                    part,
+                    answer: None,
+                    status: Status::Error(e.to_string()),
+                })
+                .collect();
+        }
+    };
+    let hash = input_hash(&input);
+
+    runner::run_input(solver, &[1, 2], &input)
+        .into_iter()
+        .map(|report| {
+            let part = report.part;
+            let answer = match report.outcome {
+                Outcome::Solved(answer) => answer,
+                outcome => {
+                    return Check {
+                        day,
+                        part,
+                        answer: None,
+                        status: Status::Error(outcome.to_string()),
+                    }
+                }
+            };
+            let status = match store.get(day, &hash, part) {
+                Some(expected) if expected == answer => Status::Pass,
+                Some(expected) => Status::Fail {
+                    expected: expected.to_string(),
+                },
+                None if record => {
+                    store.set(day, &hash, part, answer.clone());
+                    Status::Missing { recorded: true }
+                }
+                None => Status::Missing { recorded: false },
+            };
+            Check {
+                day,
+                part,
+                answer: Some(answer),
+                status,
+            }
+        })
+        .collect()
+}
+
+/// Print a table of the checks.
+pub fn print_table(checks: &[Check]) {
+    let width = checks
+        .iter()
+        .filter_map(|c| c.answer.as_ref().map(|a| a.len()))
+        .max()
+        .unwrap_or(0)
+        .max("Answer".len());
+
+    println!("{:>3}  {:>4}  {:<width$}  Status", "Day", "Part", "Answer");
+    println!("{:->3}  {:->4}  {:-<width$}  {:-<6}", "", "", "", "");
+    for c in checks {
+        println!(
+            "{:>3}  {:>4}  {:<width$}  {}",
+            c.day,
+            c.part,
+            c.answer.as_deref().unwrap_or("-"),
+            c.status
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    const SEQS: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7\n";
+
+    fn statuses(checks: &[Check]) -> Vec<&Status> {
+        checks.iter().map(|c| &c.status).collect()
+    }
+
+    #[test]
+    fn verify_against_store() {
+        let solver = registry::get(15).unwrap();
+        let source = Source::Text(SEQS.to_string());
+        let hash = input_hash(SEQS);
+
+        let mut store = AnswerStore::default();
+        let checks = verify(solver, &source, &mut store, false, Mode::Normalize);
+        assert_eq!(
+            statuses(&checks),
+            vec![
+                &Status::Missing { recorded: false },
+                &Status::Missing { recorded: false }
+            ]
+        );
+
+        store.set(15, &hash, 1, "1320".to_string());
+        store.set(15, &hash, 2, "146".to_string());
+        let checks = verify(solver, &source, &mut store, false, Mode::Normalize);
+        assert_eq!(
+            statuses(&checks),
+            vec![
+                &Status::Pass,
+                &Status::Fail {
+                    expected: "146".to_string()
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn record_missing_answers() {
+        let solver = registry::get(15).unwrap();
+        let source = Source::Text(SEQS.to_string());
+        let mut store = AnswerStore::default();
+
+        let checks = verify(solver, &source, &mut store, true, Mode::Normalize);
+        assert_eq!(
+            statuses(&checks),
+            vec![
+                &Status::Missing { recorded: true },
+                &Status::Missing { recorded: true }
+            ]
+        );
+        assert_eq!(store.get(15, &input_hash(SEQS), 2), Some("145"));
+
+        let checks = verify(solver, &source, &mut store, true, Mode::Normalize);
+        assert_eq!(statuses(&checks), vec![&Status::Pass, &Status::Pass]);
+    }
+
+    #[test]
+    fn missing_input() {
+        let solver = registry::get(15).unwrap();
+        let source = Source::from_arg("./no/such/input.txt");
+        let checks = verify(
+            solver,
+            &source,
+            &mut AnswerStore::default(),
+            true,
+            Mode::Normalize,
+        );
+        assert!(matches!(checks[0].status, Status::Error(_)));
+        assert_eq!(checks.len(), 2);
+    }
+}
@@ -0,0 +1,37 @@
+use common::parse::ParseError;
+use common::{SolveError, Solution};
+use std::fmt::Display;
+
+/// Parse the puzzle input into its lines.
+pub fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    Ok(input.lines().map(str::to_string).collect())
+}
+
+/// Return the answer of part 1.
+pub fn part1(_lines: &[String]) -> u64 {
+    0
+}
+
+/// Return the answer of part 2.
+pub fn part2(_lines: &[String]) -> u64 {
+    0
+}
+
+pub struct Day{{day}};
+
+impl Solution for Day{{day}} {
+    const DAY: u8 = {{day}};
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, SolveError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, SolveError> {
+        Ok(part2(input))
+    }
+}